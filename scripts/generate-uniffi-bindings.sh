@@ -0,0 +1,28 @@
+#!/usr/bin/env sh
+#
+# Generates the Python, Kotlin and Swift bindings of senhasegura-rs with the in-repo
+# `uniffi-bindgen`, into the given folder (defaults to `bindings`).
+
+set -eu
+
+cd "$(dirname "$0")/.."
+
+out_dir="${1:-bindings}"
+target_dir="${CARGO_TARGET_DIR:-target}"
+
+case "$(uname -s)" in
+    Darwin) library="libsenhasegura_rs.dylib" ;;
+    *) library="libsenhasegura_rs.so" ;;
+esac
+
+# The debug build keeps the UniFFI metadata in the library itself.
+cargo build -p senhasegura-rs --features uniffi
+
+cargo run -q -p senhasegura-rs --features uniffi --bin uniffi-bindgen -- \
+    generate \
+    --library "$target_dir/debug/$library" \
+    -l python \
+    -l kotlin \
+    -l swift \
+    --out-dir "$out_dir" \
+    --no-format
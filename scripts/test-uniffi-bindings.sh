@@ -0,0 +1,93 @@
+#!/usr/bin/env sh
+#
+# Runs the tests of the UniFFI bindings (see `senhasegura-rs/tests/bindings`) against
+# senhasegura-mock, for the given languages (defaults to `python kotlin swift`).
+#
+# Kotlin requires `kotlinc`, with JNA and kotlinx.coroutines in the `CLASSPATH`. Swift requires
+# `swiftc` and `swift`.
+
+set -eu
+
+cd "$(dirname "$0")/.."
+
+languages="${*:-python kotlin swift}"
+target_dir="${CARGO_TARGET_DIR:-target}"
+tests_dir="$PWD/senhasegura-rs/tests/bindings"
+
+case "$(uname -s)" in
+    Darwin) library_suffix="dylib" ;;
+    *) library_suffix="so" ;;
+esac
+
+out_dir="$(mktemp -d)"
+mock_pid=""
+trap 'if [ -n "$mock_pid" ]; then kill "$mock_pid"; fi; rm -rf "$out_dir"' EXIT
+
+scripts/generate-uniffi-bindings.sh "$out_dir"
+cp "$target_dir/debug/libsenhasegura_rs.$library_suffix" "$out_dir"
+
+cargo build -q -p senhasegura-mock
+
+# The mock server prints its base URL once it's ready.
+"$target_dir/debug/senhasegura-mock" --seed senhasegura-mock/seed.yaml >"$out_dir/mock.url" &
+mock_pid=$!
+
+while [ ! -s "$out_dir/mock.url" ]; do
+    sleep 0.1
+done
+
+SENHASEGURA_MOCK_URL="$(head -n 1 "$out_dir/mock.url")"
+export SENHASEGURA_MOCK_URL
+
+export LD_LIBRARY_PATH="$out_dir${LD_LIBRARY_PATH:+:$LD_LIBRARY_PATH}"
+export DYLD_LIBRARY_PATH="$out_dir${DYLD_LIBRARY_PATH:+:$DYLD_LIBRARY_PATH}"
+
+for language in $languages; do
+    echo "Testing $language bindings"
+
+    case "$language" in
+        python)
+            PYTHONPATH="$out_dir" python3 "$tests_dir/test_senhasegura.py"
+            ;;
+        kotlin)
+            kotlinc -nowarn \
+                -classpath "${CLASSPATH:-}" \
+                -d "$out_dir/senhasegura.jar" \
+                "$out_dir/uniffi/senhasegura/senhasegura.kt"
+
+            kotlinc -nowarn \
+                -classpath "$out_dir:$out_dir/senhasegura.jar${CLASSPATH:+:$CLASSPATH}" \
+                -J-ea \
+                -J-Djna.library.path="$out_dir" \
+                -script "$tests_dir/test_senhasegura.kts"
+            ;;
+        swift)
+            (
+                cd "$out_dir"
+
+                swiftc \
+                    -emit-module \
+                    -emit-library \
+                    -module-name senhasegura \
+                    -o "libtestmod_senhasegura.$library_suffix" \
+                    -Xcc -fmodule-map-file=senhaseguraFFI.modulemap \
+                    -I . \
+                    -L . \
+                    -lsenhasegura_rs \
+                    senhasegura.swift
+            )
+
+            swift \
+                -I "$out_dir" \
+                -L "$out_dir" \
+                -lsenhasegura_rs \
+                -ltestmod_senhasegura \
+                -Xcc -fmodule-map-file="$out_dir/senhaseguraFFI.modulemap" \
+                "$tests_dir/test_senhasegura.swift"
+            ;;
+        *)
+            echo "Unsupported language: $language" >&2
+            exit 1
+            ;;
+    esac
+done
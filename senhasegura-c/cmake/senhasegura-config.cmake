@@ -0,0 +1,48 @@
+# Package config of senhasegura-c, for `find_package(senhasegura)`.
+#
+# It's installed into `<prefix>/lib/cmake/senhasegura`, next to:
+#
+# - `<prefix>/include/senhasegura_c.h` and `<prefix>/include/senhasegura.hpp`.
+# - `<prefix>/lib/libsenhasegura_c.so` (Linux), `<prefix>/lib/libsenhasegura_c.dylib` (macOS), or
+#   `<prefix>/bin/senhasegura_c.dll` and `<prefix>/lib/senhasegura_c.dll.lib` (Windows).
+#
+# Defines the `senhasegura::senhasegura_c` target for C, and the `senhasegura::senhasegura` target
+# for C++ (i.e. the header-only wrapper, which requires C++17).
+
+get_filename_component(_senhasegura_prefix "${CMAKE_CURRENT_LIST_DIR}/../../.." ABSOLUTE)
+
+if(NOT TARGET senhasegura::senhasegura_c)
+    add_library(senhasegura::senhasegura_c SHARED IMPORTED)
+
+    set_target_properties(senhasegura::senhasegura_c PROPERTIES
+        INTERFACE_INCLUDE_DIRECTORIES "${_senhasegura_prefix}/include"
+    )
+
+    if(WIN32)
+        set_target_properties(senhasegura::senhasegura_c PROPERTIES
+            IMPORTED_LOCATION "${_senhasegura_prefix}/bin/senhasegura_c.dll"
+            IMPORTED_IMPLIB "${_senhasegura_prefix}/lib/senhasegura_c.dll.lib"
+        )
+    elseif(APPLE)
+        set_target_properties(senhasegura::senhasegura_c PROPERTIES
+            IMPORTED_LOCATION "${_senhasegura_prefix}/lib/libsenhasegura_c.dylib"
+        )
+    else()
+        set_target_properties(senhasegura::senhasegura_c PROPERTIES
+            IMPORTED_LOCATION "${_senhasegura_prefix}/lib/libsenhasegura_c.so"
+        )
+    endif()
+endif()
+
+if(NOT TARGET senhasegura::senhasegura)
+    add_library(senhasegura::senhasegura INTERFACE IMPORTED)
+
+    set_target_properties(senhasegura::senhasegura PROPERTIES
+        INTERFACE_LINK_LIBRARIES senhasegura::senhasegura_c
+        INTERFACE_COMPILE_FEATURES cxx_std_17
+    )
+endif()
+
+unset(_senhasegura_prefix)
+
+set(senhasegura_FOUND TRUE)
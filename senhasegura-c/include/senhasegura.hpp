@@ -0,0 +1,531 @@
+/**
+ * MIT License
+ *
+ * Copyright (c) 2024 Gabriel Kim
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+/**
+ * @file senhasegura.hpp
+ * @author Gabriel Kim <gabrielkim13@gmail.com>
+ * @brief Senhasegura API client for C++
+ *
+ * Header-only RAII wrapper over the auto-generated `senhasegura_c.h` header, which requires C++17.
+ *
+ * @see https://github.com/gabrielkim13/senhasegura-rs
+ */
+
+#ifndef senhasegura_hpp
+#define senhasegura_hpp
+
+#include <cstdint>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <variant>
+
+#include "senhasegura_c.h"
+
+namespace senhasegura {
+
+/**
+ * Protected information identifier, i.e. its ID or its tag.
+ */
+using ProtectedInformationIdentifier = std::variant<int32_t, std::string>;
+
+/**
+ * Response (i.e. "response") field.
+ */
+struct Response {
+    /**
+     * HTTP status code.
+     */
+    uint16_t status = 0;
+
+    /**
+     * Response message.
+     */
+    std::string message;
+
+    /**
+     * Flag to indicate whether an error occurred.
+     */
+    bool error = false;
+
+    /**
+     * Error code.
+     */
+    int32_t error_code = 0;
+};
+
+/**
+ * Exception (i.e. "exception") field.
+ */
+struct Exception {
+    /**
+     * Exception code.
+     */
+    uint16_t code = 0;
+
+    /**
+     * Exception message.
+     */
+    std::string message;
+
+    /**
+     * Exception detail (optional).
+     */
+    std::optional<std::string> detail;
+};
+
+/**
+ * API error response.
+ */
+struct ApiError {
+    /**
+     * Response.
+     */
+    Response response;
+
+    /**
+     * Exception (optional).
+     */
+    std::optional<Exception> exception;
+};
+
+/**
+ * Access protected information result (i.e. "info") field.
+ */
+struct AccessProtectedInformationResult {
+    /**
+     * Protected information item’s unique identification code.
+     */
+    int32_t id = 0;
+
+    /**
+     * Information identifier (optional).
+     */
+    std::optional<std::string> tag;
+
+    /**
+     * Information type (optional).
+     */
+    std::optional<std::string> type;
+
+    /**
+     * Information you wish to protect.
+     */
+    std::string content;
+};
+
+/**
+ * Access protected information API response.
+ */
+struct AccessProtectedInformationApiResponse {
+    /**
+     * Response.
+     */
+    Response response;
+
+    /**
+     * Access protected information result.
+     */
+    AccessProtectedInformationResult info;
+};
+
+/**
+ * Create protected information API request.
+ */
+struct CreateProtectedInformationApiRequest {
+    /**
+     * Name assigned to the protected item (optional).
+     */
+    std::optional<std::string> name;
+
+    /**
+     * Information you wish to protect.
+     */
+    std::string content;
+
+    /**
+     * Unique string to identify the protected item (optional).
+     */
+    std::optional<std::string> identifier;
+
+    /**
+     * Information type (optional).
+     */
+    std::optional<std::string> type;
+};
+
+/**
+ * Create protected information result (i.e. "info") field.
+ */
+struct CreateProtectedInformationResult {
+    /**
+     * Name assigned to the protected item (optional).
+     */
+    std::optional<std::string> name;
+
+    /**
+     * Information type (optional).
+     */
+    std::optional<std::string> type;
+
+    /**
+     * Name of the service associated to the information (optional).
+     */
+    std::optional<std::string> service;
+
+    /**
+     * URL associated to the information (optional).
+     */
+    std::optional<std::string> url;
+
+    /**
+     * Information you wish to protect.
+     */
+    std::string content;
+
+    /**
+     * Comma-separated ACL of users / groups (optional).
+     */
+    std::optional<std::string> users_allowed;
+
+    /**
+     * Unique string to identify the protected item (optional).
+     */
+    std::optional<std::string> identifier;
+};
+
+/**
+ * Create protected information API response.
+ */
+struct CreateProtectedInformationApiResponse {
+    /**
+     * Response.
+     */
+    Response response;
+
+    /**
+     * Create protected information result.
+     */
+    CreateProtectedInformationResult info;
+};
+
+/**
+ * Disable protected information API response.
+ */
+struct DisableProtectedInformationApiResponse {
+    /**
+     * Response.
+     */
+    Response response;
+};
+
+/**
+ * Senhasegura API client properties.
+ */
+struct ClientProps {
+    /**
+     * Base URL of the Senhasegura API.
+     */
+    std::string base_url;
+
+    /**
+     * OAuth2 client credentials ID.
+     */
+    std::string client_id;
+
+    /**
+     * OAuth2 client credentials secret.
+     */
+    std::string client_secret;
+
+    /**
+     * Request timeout, in seconds (0 means the default, i.e. 10 seconds).
+     */
+    uint32_t request_timeout = 0;
+
+    /**
+     * Base delay of the exponential backoff retry policy, in seconds (0 means the default, i.e. 2
+     * seconds).
+     */
+    uint32_t base_retry_delay_secs = 0;
+
+    /**
+     * Maximum number of retries (`SENHASEGURA_DEFAULT_MAX_N_RETRIES` means the default, i.e. 3
+     * retries, while 0 disables them).
+     */
+    uint32_t max_n_retries = SENHASEGURA_DEFAULT_MAX_N_RETRIES;
+};
+
+/**
+ * Error thrown by the client, with the message of the library's last error.
+ *
+ * API errors (i.e. `API` codes) also carry the API error response.
+ */
+class Error : public std::runtime_error {
+public:
+    Error(::ErrorCode code, const std::string &message, std::optional<ApiError> api_error = {})
+        : std::runtime_error(message), code_(code), api_error_(std::move(api_error)) {}
+
+    /**
+     * Error code.
+     */
+    ::ErrorCode code() const noexcept { return code_; }
+
+    /**
+     * API error response, only set on API errors.
+     */
+    const std::optional<ApiError> &api_error() const noexcept { return api_error_; }
+
+private:
+    ::ErrorCode code_;
+    std::optional<ApiError> api_error_;
+};
+
+namespace detail {
+
+/**
+ * C struct whose library-allocated strings are freed once it goes out of scope.
+ */
+template <typename T, void (*Free)(T *)> struct Owned {
+    T value{};
+
+    Owned() = default;
+    Owned(const Owned &) = delete;
+    Owned &operator=(const Owned &) = delete;
+
+    ~Owned() { Free(&value); }
+};
+
+inline std::string to_string(const char *s) { return s != nullptr ? std::string(s) : std::string(); }
+
+inline std::string to_string(const char *s, size_t len) {
+    return s != nullptr ? std::string(s, len) : std::string();
+}
+
+inline std::optional<std::string> to_optional(const char *s) {
+    if (s == nullptr) {
+        return std::nullopt;
+    }
+
+    return std::string(s);
+}
+
+inline const char *to_c_str(const std::optional<std::string> &s) {
+    return s.has_value() ? s->c_str() : nullptr;
+}
+
+inline Response convert(const ::Response &response) {
+    return Response{response.status, to_string(response.message), response.error,
+                    response.error_code};
+}
+
+inline ApiError convert(const ::ApiError &error) {
+    ApiError api_error{convert(error.response), std::nullopt};
+
+    // The exception is absent if its (required) message is.
+    if (error.exception.message != nullptr) {
+        api_error.exception = Exception{error.exception.code, to_string(error.exception.message),
+                                        to_optional(error.exception.detail)};
+    }
+
+    return api_error;
+}
+
+/**
+ * Throws an `Error` if the call failed, with the API error response (if any).
+ */
+inline void check(::ErrorCode code, const ::ApiError *error = nullptr) {
+    if (code == ::OK) {
+        return;
+    }
+
+    const char *message = senhasegura_last_error_message();
+
+    std::optional<ApiError> api_error;
+    if (code == ::API && error != nullptr) {
+        api_error = convert(*error);
+    }
+
+    throw Error(code, message != nullptr ? message : "unknown error", std::move(api_error));
+}
+
+/**
+ * Converts the identifier, which borrows the tag (if any).
+ */
+inline ::ProtectedInformationIdentifier convert(const ProtectedInformationIdentifier &id) {
+    ::ProtectedInformationIdentifier c_id{};
+
+    if (const auto *tag = std::get_if<std::string>(&id)) {
+        c_id.kind = ::TAG;
+        c_id.tag = tag->c_str();
+    } else {
+        c_id.kind = ::ID;
+        c_id.id = std::get<int32_t>(id);
+    }
+
+    return c_id;
+}
+
+} // namespace detail
+
+/**
+ * Senhasegura API client, which owns the underlying `SenhaseguraClient` handle.
+ *
+ * Clients may be moved, but not copied. Its methods are thread-safe, and block until the request
+ * completes; the handle may be used with the C functions for anything else (e.g. asynchronous
+ * requests).
+ */
+class Client {
+public:
+    /**
+     * Initializes the client.
+     *
+     * @throws Error If the properties are invalid (e.g. `INVALID_URL`).
+     */
+    explicit Client(const ClientProps &props) {
+        ::SenhaseguraClientProps c_props{};
+        c_props.base_url = props.base_url.c_str();
+        c_props.request_timeout = props.request_timeout;
+        c_props.client_id = props.client_id.c_str();
+        c_props.client_secret = props.client_secret.c_str();
+        c_props.base_retry_delay_secs = props.base_retry_delay_secs;
+        c_props.max_n_retries = props.max_n_retries;
+
+        detail::check(create_senhasegura_client(&handle_, &c_props));
+    }
+
+    Client(const Client &) = delete;
+    Client &operator=(const Client &) = delete;
+
+    Client(Client &&other) noexcept : handle_(std::exchange(other.handle_, nullptr)) {}
+
+    Client &operator=(Client &&other) noexcept {
+        if (this != &other) {
+            destroy_senhasegura_client(handle_);
+            handle_ = std::exchange(other.handle_, nullptr);
+        }
+
+        return *this;
+    }
+
+    ~Client() { destroy_senhasegura_client(handle_); }
+
+    /**
+     * Underlying handle, which is null if the client was moved from.
+     */
+    ::SenhaseguraClient *handle() const noexcept { return handle_; }
+
+    /**
+     * Access protected information.
+     *
+     * @see https://docs.senhasegura.io/docs/a2a-pam-core-access-protected-information.
+     *
+     * @throws Error If the request failed.
+     */
+    AccessProtectedInformationApiResponse
+    access_protected_information(const ProtectedInformationIdentifier &id) const {
+        detail::Owned<::AccessProtectedInformationApiResponse,
+                      free_access_protected_information_api_response>
+            response;
+        detail::Owned<::ApiError, free_api_error> error;
+
+        detail::check(::access_protected_information(handle_, detail::convert(id), &response.value,
+                                                   &error.value),
+                      &error.value);
+
+        const auto &info = response.value.info;
+
+        return AccessProtectedInformationApiResponse{
+            detail::convert(response.value.response),
+            AccessProtectedInformationResult{info.id, detail::to_optional(info.tag),
+                                             detail::to_optional(info.type),
+                                             detail::to_string(info.content, info.content_len)},
+        };
+    }
+
+    /**
+     * Create protected information.
+     *
+     * @see https://docs.senhasegura.io/docs/a2a-pam-core-create-protected-information.
+     *
+     * @throws Error If the request failed.
+     */
+    CreateProtectedInformationApiResponse
+    create_protected_information(const CreateProtectedInformationApiRequest &request) const {
+        ::CreateProtectedInformationApiRequest c_request{};
+        c_request.name = detail::to_c_str(request.name);
+        c_request.content = request.content.c_str();
+        c_request.identifier = detail::to_c_str(request.identifier);
+        c_request.type = detail::to_c_str(request.type);
+
+        detail::Owned<::CreateProtectedInformationApiResponse,
+                      free_create_protected_information_api_response>
+            response;
+        detail::Owned<::ApiError, free_api_error> error;
+
+        detail::check(
+            ::create_protected_information(handle_, &c_request, &response.value, &error.value),
+            &error.value);
+
+        const auto &info = response.value.info;
+
+        return CreateProtectedInformationApiResponse{
+            detail::convert(response.value.response),
+            CreateProtectedInformationResult{
+                detail::to_optional(info.name), detail::to_optional(info.type),
+                detail::to_optional(info.service), detail::to_optional(info.url),
+                detail::to_string(info.content, info.content_len), detail::to_optional(info.users_allowed),
+                detail::to_optional(info.identifier)},
+        };
+    }
+
+    /**
+     * Disable protected information.
+     *
+     * @see https://docs.senhasegura.io/docs/a2a-pam-core-disable-protected-information.
+     *
+     * @throws Error If the request failed.
+     */
+    DisableProtectedInformationApiResponse
+    disable_protected_information(const ProtectedInformationIdentifier &id) const {
+        detail::Owned<::DisableProtectedInformationApiResponse,
+                      free_disable_protected_information_api_response>
+            response;
+        detail::Owned<::ApiError, free_api_error> error;
+
+        detail::check(::disable_protected_information(handle_, detail::convert(id), &response.value,
+                                                    &error.value),
+                      &error.value);
+
+        return DisableProtectedInformationApiResponse{detail::convert(response.value.response)};
+    }
+
+private:
+    ::SenhaseguraClient *handle_ = nullptr;
+};
+
+} // namespace senhasegura
+
+#endif // senhasegura_hpp
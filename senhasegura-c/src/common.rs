@@ -9,30 +9,58 @@ where
     fn assign(&mut self, value: T) -> Result<(), Error>;
 }
 
+/// Struct with library-allocated strings, which must be freed by the library.
+pub(crate) trait FreeStrings {
+    /// Frees the strings, setting them to null.
+    fn free_strings(&mut self);
+}
+
 pub(crate) fn handle_result<TR, TC>(
     result: Result<TR, senhasegura_rs::Error>,
     response: &mut TC,
     error: &mut ApiError,
 ) -> Result<(), Error>
 where
-    TC: TryAssignFrom<TR>,
+    TC: TryAssignFrom<TR> + FreeStrings + Default,
 {
     match result {
         Ok(value) => {
-            response.assign(value)?;
+            *response = assign_new(value)?;
 
             Ok(())
         }
         Err(senhasegura_rs::Error::Api(api_error)) => {
-            error.assign(api_error)?;
+            let message = api_error.to_string();
+
+            *error = assign_new(api_error)?;
 
-            Err(Error::api())
+            Err(Error::api(message))
         }
         Err(e) => Err(e.into()),
     }
 }
 
+/// Assigns the value to a new struct, freeing its strings if the assignment fails, so that the
+/// caller's struct is only overwritten once every string is allocated.
+fn assign_new<TR, TC>(value: TR) -> Result<TC, Error>
+where
+    TC: TryAssignFrom<TR> + FreeStrings + Default,
+{
+    let mut c = TC::default();
+
+    if let Err(e) = c.assign(value) {
+        c.free_strings();
+
+        return Err(e);
+    }
+
+    Ok(c)
+}
+
 /// API error response.
+///
+/// Its strings are allocated by the library, and must be freed with `free_api_error`.
+#[derive(Default)]
 #[repr(C)]
 pub struct ApiError {
     /// Response.
@@ -54,6 +82,28 @@ impl TryAssignFrom<senhasegura_rs::ApiError> for ApiError {
     }
 }
 
+impl FreeStrings for ApiError {
+    fn free_strings(&mut self) {
+        self.response.free_strings();
+        self.exception.free_strings();
+    }
+}
+
+/// Frees the strings of an API error response, which was filled by the library.
+///
+/// The struct itself is owned by the caller, and may be reused afterwards.
+///
+/// # Safety
+///
+/// - The `error` parameter must be null, or a valid pointer to an `ApiError` struct whose strings
+///   were allocated by the library (or are null).
+#[no_mangle]
+pub unsafe extern "C" fn free_api_error(error: *mut ApiError) {
+    if let Some(error) = unsafe { error.as_mut() } {
+        error.free_strings();
+    }
+}
+
 /// Response (i.e. "response") field.
 #[repr(C)]
 pub struct Response {
@@ -73,7 +123,7 @@ pub struct Response {
 impl TryAssignFrom<senhasegura_rs::Response> for Response {
     fn assign(&mut self, value: senhasegura_rs::Response) -> Result<(), Error> {
         self.status = value.status.as_u16();
-        assign_str_to_c_char(&value.message, self.message)?;
+        self.message = str_to_c_char(&value.message)?;
         self.error = value.error;
         self.error_code = value.error_code;
 
@@ -81,8 +131,24 @@ impl TryAssignFrom<senhasegura_rs::Response> for Response {
     }
 }
 
+impl FreeStrings for Response {
+    fn free_strings(&mut self) {
+        free_c_char(&mut self.message);
+    }
+}
+
+impl Default for Response {
+    fn default() -> Self {
+        Self {
+            status: 0,
+            message: std::ptr::null_mut(),
+            error: false,
+            error_code: 0,
+        }
+    }
+}
+
 /// Exception (i.e. "exception") field.
-#[derive(Clone, Copy)]
 #[repr(C)]
 pub struct Exception {
     /// Exception code.
@@ -99,19 +165,33 @@ impl TryAssignFrom<senhasegura_rs::Exception> for Exception {
     fn assign(&mut self, value: senhasegura_rs::Exception) -> Result<(), Error> {
         self.code = value.code.into();
 
-        assign_str_to_c_char(&value.message, self.message)?;
-
-        if let Some(detail) = value.detail {
-            assign_str_to_c_char(&detail, self.detail)?;
-        }
+        self.message = str_to_c_char(&value.message)?;
+        self.detail = option_str_to_c_char(value.detail.as_deref())?;
 
         Ok(())
     }
 }
 
-pub(crate) fn c_char_to_string(c: *const c_char) -> Result<String, Error> {
+impl FreeStrings for Exception {
+    fn free_strings(&mut self) {
+        free_c_char(&mut self.message);
+        free_c_char(&mut self.detail);
+    }
+}
+
+impl Default for Exception {
+    fn default() -> Self {
+        Self {
+            code: 0,
+            message: std::ptr::null_mut(),
+            detail: std::ptr::null_mut(),
+        }
+    }
+}
+
+pub(crate) fn c_char_to_string(c: *const c_char, name: &str) -> Result<String, Error> {
     if c.is_null() {
-        return Err(Error::invalid_null_pointer());
+        return Err(Error::invalid_null_pointer(name));
     }
 
     let c_str = unsafe { std::ffi::CStr::from_ptr(c) };
@@ -119,19 +199,57 @@ pub(crate) fn c_char_to_string(c: *const c_char) -> Result<String, Error> {
     c_str
         .to_str()
         .map(|s| s.to_string())
-        .map_err(|_| Error::invalid_string())
+        .map_err(|e| Error::invalid_string(format!("`{name}`: {e}")))
 }
 
-pub(crate) fn assign_str_to_c_char(src: &str, dst: *mut c_char) -> Result<(), Error> {
-    if dst.is_null() {
-        return Err(Error::invalid_null_pointer());
+/// Allocates a NUL-terminated copy of the string, which must be freed with `free_c_char`.
+///
+/// Strings with embedded NUL characters can't be represented, and are rejected.
+pub(crate) fn str_to_c_char(src: &str) -> Result<*mut c_char, Error> {
+    let c_str = std::ffi::CString::new(src).map_err(Error::invalid_string)?;
+
+    Ok(c_str.into_raw())
+}
+
+/// Allocates a NUL-terminated copy of the string, returning it along with its length (in bytes and
+/// excluding the NUL terminator), which must be freed with `free_c_char_with_len`.
+///
+/// Unlike `str_to_c_char`, strings with embedded NUL characters are allowed, thus they must be read
+/// by their length.
+pub(crate) fn str_to_c_char_with_len(src: &str) -> (*mut c_char, usize) {
+    let mut bytes = Vec::with_capacity(src.len() + 1);
+    bytes.extend_from_slice(src.as_bytes());
+    bytes.push(0);
+
+    (Box::into_raw(bytes.into_boxed_slice()).cast(), src.len())
+}
+
+/// Allocates a NUL-terminated copy of the string, if any, or returns null.
+pub(crate) fn option_str_to_c_char(src: Option<&str>) -> Result<*mut c_char, Error> {
+    match src {
+        Some(src) => str_to_c_char(src),
+        None => Ok(std::ptr::null_mut()),
     }
+}
+
+/// Frees a string allocated by `str_to_c_char`, if not null, setting it to null.
+pub(crate) fn free_c_char(c: &mut *mut c_char) {
+    if !c.is_null() {
+        drop(unsafe { std::ffi::CString::from_raw(*c) });
+    }
+
+    *c = std::ptr::null_mut();
+}
 
-    let c_str = std::ffi::CString::new(src).map_err(|_| Error::invalid_string())?;
+/// Frees a string allocated by `str_to_c_char_with_len`, if not null, setting it to null and its
+/// length to 0.
+pub(crate) fn free_c_char_with_len(c: &mut *mut c_char, len: &mut usize) {
+    if !c.is_null() {
+        let bytes = std::ptr::slice_from_raw_parts_mut(c.cast::<u8>(), *len + 1);
 
-    unsafe {
-        std::ptr::copy_nonoverlapping(c_str.as_ptr(), dst, c_str.as_bytes().len());
+        drop(unsafe { Box::from_raw(bytes) });
     }
 
-    Ok(())
+    *c = std::ptr::null_mut();
+    *len = 0;
 }
@@ -1,66 +1,164 @@
+use std::{cell::RefCell, ffi::CString, fmt::Display, os::raw::c_char};
+
 /// Senhasegura API client error codes.
 #[derive(thiserror::Error, Debug)]
 #[repr(C)]
 pub enum ErrorCode {
+    /// Success.
     #[error("OK")]
     Ok,
 
+    /// Senhasegura API error (see the `ApiError` struct).
     #[error("API error")]
     Api,
 
+    /// HTTP transport error.
     #[error("Transport error")]
     Transport,
 
+    /// Other error.
     #[error("Other error (Rust)")]
     Other,
 
+    /// Client wasn't initialized.
     #[error("Client not initialized")]
     ClientNotInitialized,
 
+    /// Required pointer is null.
     #[error("Invalid null pointer")]
     InvalidNullPointer,
 
+    /// String isn't valid UTF-8, or has embedded NUL characters.
     #[error("Invalid UTF-8 string")]
     InvalidString,
 
+    /// URL is invalid.
     #[error("Invalid URL")]
     InvalidUrl,
+
+    /// Asynchronous request was cancelled.
+    #[error("Cancelled")]
+    Cancelled,
+
+    /// Library panicked (i.e. a bug), which was caught at the FFI boundary.
+    #[error("Panic")]
+    Panic,
+
+    /// Argument is invalid (e.g. an unknown enum value).
+    #[error("Invalid argument")]
+    InvalidArgument,
+}
+
+thread_local! {
+    /// Message of the last error which occurred on the thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Returns the message of the last error which occurred on the calling thread (i.e. the full
+/// error chain of the last call which didn't return `OK`), or null if none did.
+///
+/// The string is owned by the library, and remains valid until the next failed call on the same
+/// thread.
+#[no_mangle]
+pub extern "C" fn senhasegura_last_error_message() -> *const c_char {
+    LAST_ERROR.with_borrow(|last_error| match last_error {
+        Some(message) => message.as_ptr(),
+        None => std::ptr::null(),
+    })
+}
+
+/// Returns the length, in bytes and excluding the NUL terminator, of the message of the last
+/// error which occurred on the calling thread, or 0 if none did.
+#[no_mangle]
+pub extern "C" fn senhasegura_last_error_length() -> usize {
+    LAST_ERROR.with_borrow(|last_error| {
+        last_error
+            .as_ref()
+            .map(|message| message.as_bytes().len())
+            .unwrap_or_default()
+    })
+}
+
+fn set_last_error(message: &str) {
+    let message = CString::new(message.replace('\0', "\\0")).unwrap_or_default();
+
+    LAST_ERROR.set(Some(message));
 }
 
 /// cbindgen:no-export
 #[derive(thiserror::Error, Debug)]
-#[error(transparent)]
-pub struct Error(ErrorCode);
+#[error("{message}")]
+pub struct Error {
+    code: ErrorCode,
+    message: String,
+}
 
 impl Error {
-    pub fn api() -> Self {
-        Self(ErrorCode::Api)
+    pub fn api(message: impl Display) -> Self {
+        Self::new(ErrorCode::Api, message)
+    }
+
+    pub fn transport(message: impl Display) -> Self {
+        Self::new(ErrorCode::Transport, message)
     }
 
-    pub fn transport() -> Self {
-        Self(ErrorCode::Transport)
+    pub fn other(message: impl Display) -> Self {
+        Self::new(ErrorCode::Other, message)
     }
 
-    pub fn other() -> Self {
-        Self(ErrorCode::Other)
+    pub fn client_not_initialized() -> Self {
+        Self::new(ErrorCode::ClientNotInitialized, "client not initialized")
     }
 
-    pub fn invalid_null_pointer() -> Self {
-        Self(ErrorCode::InvalidNullPointer)
+    pub fn invalid_null_pointer(name: &str) -> Self {
+        Self::new(
+            ErrorCode::InvalidNullPointer,
+            format!("invalid null pointer: `{name}`"),
+        )
     }
 
-    pub fn invalid_string() -> Self {
-        Self(ErrorCode::InvalidString)
+    pub fn invalid_string(message: impl Display) -> Self {
+        Self::new(
+            ErrorCode::InvalidString,
+            format!("invalid string: {message}"),
+        )
     }
 
-    pub fn invalid_url() -> Self {
-        Self(ErrorCode::InvalidUrl)
+    pub fn invalid_url(message: impl Display) -> Self {
+        Self::new(ErrorCode::InvalidUrl, format!("invalid URL: {message}"))
+    }
+
+    pub fn invalid_argument(message: impl Display) -> Self {
+        Self::new(
+            ErrorCode::InvalidArgument,
+            format!("invalid argument: {message}"),
+        )
+    }
+
+    pub fn cancelled() -> Self {
+        Self::new(ErrorCode::Cancelled, "request cancelled")
+    }
+
+    pub fn panic(message: impl Display) -> Self {
+        Self::new(ErrorCode::Panic, format!("panic: {message}"))
+    }
+
+    fn new(code: ErrorCode, message: impl Display) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
     }
 }
 
+/// Converts the error into its code, storing its message as the last error of the thread.
 impl From<Error> for ErrorCode {
     fn from(value: Error) -> Self {
-        value.0
+        tracing::debug!(code = ?value.code, error = %value.message, "call failed");
+
+        set_last_error(&value.message);
+
+        value.code
     }
 }
 
@@ -75,10 +173,60 @@ impl<T> From<Result<T, Error>> for ErrorCode {
 
 impl From<senhasegura_rs::Error> for Error {
     fn from(value: senhasegura_rs::Error) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&senhasegura_rs::Error> for Error {
+    fn from(value: &senhasegura_rs::Error) -> Self {
+        let message = error_chain(value);
+
         match value {
-            senhasegura_rs::Error::Api(_) => Error::api(),
-            senhasegura_rs::Error::Transport(_) => Error::transport(),
-            senhasegura_rs::Error::Other(_) => Error::other(),
+            senhasegura_rs::Error::Api(_) => Error::api(message),
+            senhasegura_rs::Error::Transport(_) => Error::transport(message),
+            senhasegura_rs::Error::Other(_) => Error::other(message),
         }
     }
 }
+
+/// Runs the body of an exported function, converting panics into errors, since unwinding across
+/// the FFI boundary is undefined behavior.
+///
+/// Panics can only be caught when built with `panic = "unwind"`, otherwise they abort the process.
+pub(crate) fn catch_panic(f: impl FnOnce() -> ErrorCode) -> ErrorCode {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(code) => code,
+        Err(payload) => {
+            let message = match payload.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => match payload.downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => "unknown panic".to_string(),
+                },
+            };
+
+            tracing::error!(%message, "caught panic");
+
+            Error::panic(message).into()
+        }
+    }
+}
+
+/// Formats the error, followed by its sources (skipping those already in the message, since some
+/// errors embed their source in their own message).
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+
+    let mut source = error.source();
+    while let Some(error) = source {
+        let text = error.to_string();
+        if !message.ends_with(&text) {
+            message.push_str(": ");
+            message.push_str(&text);
+        }
+
+        source = error.source();
+    }
+
+    message
+}
@@ -7,16 +7,28 @@ pub use pam_core::*;
 
 mod common;
 use common::*;
+pub use common::{free_api_error, ApiError, Exception, Response};
 
 mod error;
 use error::*;
+pub use error::{senhasegura_last_error_length, senhasegura_last_error_message, ErrorCode};
 
-use std::{os::raw::c_char, time::Duration};
+mod log;
+pub use log::*;
+
+mod request;
+use request::*;
+pub use request::{cancel_senhasegura_request, free_senhasegura_request, SenhaseguraRequest};
+
+use std::{os::raw::c_char, sync::Arc, time::Duration};
 
 use url::Url;
 
+/// Value of `SenhaseguraClientProps::max_n_retries` which means the default, i.e. 3 retries.
+pub const SENHASEGURA_DEFAULT_MAX_N_RETRIES: u32 = u32::MAX;
+
 /// SenhaSegura API client opaque struct.
-pub struct SenhaseguraClient(senhasegura_rs::SenhaseguraClient);
+pub struct SenhaseguraClient(Arc<senhasegura_rs::SenhaseguraClient>);
 
 /// Senhasegura API client properties.
 #[repr(C)]
@@ -24,7 +36,7 @@ pub struct SenhaseguraClientProps {
     /// Base URL of the Senhasegura API.
     pub base_url: *const c_char,
 
-    /// Request timeout, in seconds.
+    /// Request timeout, in seconds (0 means the default, i.e. 10 seconds).
     pub request_timeout: u32,
 
     /// OAuth2 client credentials ID.
@@ -32,16 +44,21 @@ pub struct SenhaseguraClientProps {
     /// OAuth2 client credentials secret.
     pub client_secret: *const c_char,
 
-    /// Base delay of the exponential backoff retry policy, in milliseconds.
+    /// Base delay of the exponential backoff retry policy, in seconds (0 means the default, i.e. 2
+    /// seconds).
     pub base_retry_delay_secs: u32,
-    /// Maximum number of retries.
+    /// Maximum number of retries (`SENHASEGURA_DEFAULT_MAX_N_RETRIES` means the default, i.e. 3
+    /// retries, while 0 disables them).
     pub max_n_retries: u32,
 }
 
 /// Initializes the Senhasegura API client.
 ///
+/// Once no longer needed, the client must be destroyed with `destroy_senhasegura_client`.
+///
 /// # Safety
 ///
+/// - The `client` parameter must be a valid pointer to a `SenhaseguraClient` pointer.
 /// - The `props` parameter must be a valid pointer to a `SenhaseguraClientProps` struct.
 /// - The `*const c_char` fields must be valid C-style strings.
 #[no_mangle]
@@ -49,47 +66,72 @@ pub unsafe extern "C" fn create_senhasegura_client(
     client: *mut *mut SenhaseguraClient,
     props: *mut SenhaseguraClientProps,
 ) -> ErrorCode {
-    let props = match unsafe { props.as_ref() } {
-        Some(props) => props,
-        None => return ErrorCode::InvalidNullPointer,
-    };
+    catch_panic(|| {
+        if client.is_null() {
+            return Error::invalid_null_pointer("client").into();
+        }
+
+        let props = match unsafe { props.as_ref() } {
+            Some(props) => props,
+            None => return Error::invalid_null_pointer("props").into(),
+        };
 
-    match _create_senhasegura_client(props) {
-        Ok(c) => {
-            *client = Box::into_raw(Box::new(c));
+        match _create_senhasegura_client(props) {
+            Ok(c) => {
+                unsafe { *client = Box::into_raw(Box::new(c)) };
 
-            ErrorCode::Ok
+                ErrorCode::Ok
+            }
+            Err(e) => e.into(),
         }
-        Err(e) => e.into(),
-    }
+    })
 }
 
 fn _create_senhasegura_client(props: &SenhaseguraClientProps) -> Result<SenhaseguraClient, Error> {
     let base_url = {
-        let base_url = c_char_to_string(props.base_url)?;
+        let base_url = c_char_to_string(props.base_url, "base_url")?;
 
-        Url::parse(&base_url).map_err(|_| Error::invalid_url())?
+        Url::parse(&base_url).map_err(Error::invalid_url)?
     };
 
-    let client_id = c_char_to_string(props.client_id)?;
-    let client_secret = c_char_to_string(props.client_secret)?;
+    let client_id = c_char_to_string(props.client_id, "client_id")?;
+    let client_secret = c_char_to_string(props.client_secret, "client_secret")?;
+
+    let mut builder =
+        senhasegura_rs::SenhaseguraClient::builder(base_url, client_id, client_secret);
 
-    let rs_client = senhasegura_rs::SenhaseguraClient::builder(base_url, client_id, client_secret)
-        .request_timeout(Duration::from_secs(props.request_timeout as u64))
-        .base_retry_delay_secs(props.base_retry_delay_secs)
-        .max_n_retries(props.max_n_retries)
-        .build()
-        .unwrap();
+    if props.request_timeout > 0 {
+        builder = builder.request_timeout(Duration::from_secs(props.request_timeout as u64));
+    }
+
+    if props.base_retry_delay_secs > 0 {
+        builder = builder.base_retry_delay_secs(props.base_retry_delay_secs);
+    }
+
+    if props.max_n_retries != SENHASEGURA_DEFAULT_MAX_N_RETRIES {
+        builder = builder.max_n_retries(props.max_n_retries);
+    }
 
-    Ok(SenhaseguraClient(rs_client))
+    Ok(SenhaseguraClient(Arc::new(builder.build()?)))
 }
 
 /// Deinitializes the Senhasegura API client.
 ///
+/// Asynchronous requests in progress keep running, and still invoke their callbacks.
+///
 /// # Safety
 ///
-/// - The `client` parameter must be a valid pointer to a `SenhaseguraClient` struct.
+/// - The `client` parameter must be null, or a valid pointer to a `SenhaseguraClient` struct
+///   which wasn't destroyed yet.
 #[no_mangle]
 pub unsafe extern "C" fn destroy_senhasegura_client(client: *mut SenhaseguraClient) {
-    drop(unsafe { Box::from_raw(client) });
+    if client.is_null() {
+        return;
+    }
+
+    catch_panic(|| {
+        drop(unsafe { Box::from_raw(client) });
+
+        ErrorCode::Ok
+    });
 }
@@ -0,0 +1,285 @@
+use std::{
+    cell::Cell,
+    ffi::{c_void, CString},
+    fmt::Write,
+    os::raw::c_char,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        OnceLock, RwLock,
+    },
+};
+
+use tracing::{
+    field::{Field, Visit},
+    subscriber::Interest,
+    Event, Metadata, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+use crate::{catch_panic, Error, ErrorCode};
+
+/// Targets of the events which are forwarded, i.e. those of the library itself (and not of its
+/// dependencies, e.g. the HTTP client, whose events might have headers with credentials).
+const TARGETS: [&str; 2] = ["senhasegura_rs", "senhasegura_c"];
+
+/// Fragments of the names of fields whose values are redacted.
+const REDACTED_FIELDS: [&str; 6] = [
+    "secret",
+    "password",
+    "token",
+    "content",
+    "authorization",
+    "credential",
+];
+
+/// Log level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub enum LogLevel {
+    /// Logging is disabled.
+    Off = 0,
+
+    /// Errors.
+    Error = 1,
+
+    /// Warnings.
+    Warn = 2,
+
+    /// Informational messages (e.g. API operations).
+    Info = 3,
+
+    /// Debug messages.
+    Debug = 4,
+
+    /// Trace messages (i.e. very verbose).
+    Trace = 5,
+}
+
+impl TryFrom<u32> for LogLevel {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::Error),
+            2 => Ok(Self::Warn),
+            3 => Ok(Self::Info),
+            4 => Ok(Self::Debug),
+            5 => Ok(Self::Trace),
+            _ => Err(Error::invalid_argument(format!(
+                "unknown log level: {value}"
+            ))),
+        }
+    }
+}
+
+impl LogLevel {
+    fn from_u8(level: u8) -> Self {
+        match level {
+            1 => Self::Error,
+            2 => Self::Warn,
+            3 => Self::Info,
+            4 => Self::Debug,
+            5 => Self::Trace,
+            _ => Self::Off,
+        }
+    }
+}
+
+impl From<&tracing::Level> for LogLevel {
+    fn from(level: &tracing::Level) -> Self {
+        match *level {
+            tracing::Level::ERROR => Self::Error,
+            tracing::Level::WARN => Self::Warn,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::DEBUG => Self::Debug,
+            tracing::Level::TRACE => Self::Trace,
+        }
+    }
+}
+
+/// Log callback, which receives the level (see `LogLevel`), target (i.e. module) and message of
+/// each event.
+///
+/// It may be invoked concurrently, from any thread (including those of the library), thus it
+/// should be thread-safe and shouldn't block. The strings are only valid until it returns.
+pub type LogCallback = extern "C" fn(
+    level: u32,
+    target: *const c_char,
+    message: *const c_char,
+    user_data: *mut c_void,
+);
+
+struct Sink {
+    callback: LogCallback,
+    user_data: *mut c_void,
+}
+
+// The library never dereferences the user data, and the caller is responsible for its thread
+// safety.
+unsafe impl Send for Sink {}
+unsafe impl Sync for Sink {}
+
+static LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Off as u8);
+static SINK: RwLock<Option<Sink>> = RwLock::new(None);
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+thread_local! {
+    /// Flag to indicate whether the callback is being invoked on the thread, so that events
+    /// emitted by it (e.g. by calling the library back) aren't forwarded recursively.
+    static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets the log callback, which receives the events of the library up to the level (see
+/// `LogLevel`), with the user data. A null callback disables logging.
+///
+/// Values of fields which might hold secrets (e.g. client secrets, access tokens and protected
+/// information content) are redacted, and only events of the library itself are forwarded.
+/// Redaction is by field name only: event messages, and fields with other names (e.g. `error`),
+/// are forwarded verbatim. The library never puts secrets in them, but errors may include messages
+/// of the API (or of the host, e.g. for invalid arguments).
+///
+/// Logging is implemented by installing a global `tracing` subscriber, thus this fails if the host
+/// application (e.g. a Rust one) already installed its own. Unknown levels are rejected with
+/// `INVALID_ARGUMENT`.
+#[no_mangle]
+pub extern "C" fn senhasegura_set_log_callback(
+    level: u32,
+    callback: Option<LogCallback>,
+    user_data: *mut c_void,
+) -> ErrorCode {
+    catch_panic(|| {
+        let level = match LogLevel::try_from(level) {
+            Ok(level) => level,
+            Err(e) => return e.into(),
+        };
+
+        let installed = *INSTALLED.get_or_init(|| {
+            let subscriber = tracing_subscriber::registry().with(CallbackLayer);
+
+            tracing::subscriber::set_global_default(subscriber).is_ok()
+        });
+
+        if !installed {
+            return Error::other("a global tracing subscriber is already installed").into();
+        }
+
+        *SINK.write().unwrap_or_else(|e| e.into_inner()) = callback.map(|callback| Sink {
+            callback,
+            user_data,
+        });
+
+        LEVEL.store(level as u8, Ordering::Relaxed);
+
+        ErrorCode::Ok
+    })
+}
+
+/// Sets the level (see `LogLevel`) up to which events are forwarded to the log callback.
+///
+/// Unknown levels are rejected with `INVALID_ARGUMENT`, leaving the current one unchanged.
+#[no_mangle]
+pub extern "C" fn senhasegura_set_log_level(level: u32) -> ErrorCode {
+    catch_panic(|| match LogLevel::try_from(level) {
+        Ok(level) => {
+            LEVEL.store(level as u8, Ordering::Relaxed);
+
+            ErrorCode::Ok
+        }
+        Err(e) => e.into(),
+    })
+}
+
+/// Layer which forwards events to the log callback.
+struct CallbackLayer;
+
+impl<S> Layer<S> for CallbackLayer
+where
+    S: Subscriber,
+{
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if TARGETS
+            .iter()
+            .any(|target| metadata.target().starts_with(target))
+        {
+            // The level might change at runtime, thus events are always evaluated.
+            Interest::sometimes()
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        LogLevel::from(metadata.level()) <= LogLevel::from_u8(LEVEL.load(Ordering::Relaxed))
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if IN_CALLBACK.get() {
+            return;
+        }
+
+        let sink = SINK.read().unwrap_or_else(|e| e.into_inner());
+        let Some(sink) = sink.as_ref() else {
+            return;
+        };
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+
+        let target = to_c_string(metadata.target());
+        let message = to_c_string(&visitor.message());
+
+        IN_CALLBACK.set(true);
+        (sink.callback)(
+            LogLevel::from(metadata.level()) as u32,
+            target.as_ptr(),
+            message.as_ptr(),
+            sink.user_data,
+        );
+        IN_CALLBACK.set(false);
+    }
+}
+
+/// Formats the message of an event, followed by its other fields (i.e. `key=value`), redacting
+/// those which might hold secrets.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn message(self) -> String {
+        let mut message = self.message;
+        message.push_str(&self.fields);
+
+        message.trim_start().to_string()
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let name = field.name();
+
+        if name == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else if REDACTED_FIELDS
+            .iter()
+            .any(|fragment| name.to_lowercase().contains(fragment))
+        {
+            let _ = write!(self.fields, " {name}=[REDACTED]");
+        } else {
+            let _ = write!(self.fields, " {name}={value:?}");
+        }
+    }
+}
+
+fn to_c_string(s: &str) -> CString {
+    CString::new(s.replace('\0', "\\0")).unwrap_or_default()
+}
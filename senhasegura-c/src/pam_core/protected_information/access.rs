@@ -1,11 +1,18 @@
-use std::ffi::c_char;
+use std::ffi::{c_char, c_void};
+
+use senhasegura_rs::AccessProtectedInformationApi;
 
 use crate::{
-    assign_str_to_c_char, c_char_to_string, handle_result, ApiError, Error, ErrorCode, Response,
-    SenhaseguraClient, TryAssignFrom,
+    catch_panic, free_c_char, free_c_char_with_len, handle_result, option_str_to_c_char,
+    spawn_request, str_to_c_char_with_len, ApiError, Error, ErrorCode, FreeStrings,
+    ProtectedInformationIdentifier, Response, SenhaseguraClient, SenhaseguraRequest, TryAssignFrom,
 };
 
 /// Access protected information API response.
+///
+/// Its strings are allocated by the library, and must be freed with
+/// `free_access_protected_information_api_response`.
+#[derive(Default)]
 #[repr(C)]
 pub struct AccessProtectedInformationApiResponse {
     /// Response.
@@ -29,6 +36,13 @@ impl TryAssignFrom<senhasegura_rs::AccessProtectedInformationApiResponse>
     }
 }
 
+impl FreeStrings for AccessProtectedInformationApiResponse {
+    fn free_strings(&mut self) {
+        self.response.free_strings();
+        self.info.free_strings();
+    }
+}
+
 /// Access protected information result (i.e. "info") field.
 #[repr(C)]
 pub struct AccessProtectedInformationResult {
@@ -42,7 +56,12 @@ pub struct AccessProtectedInformationResult {
     pub r#type: *mut c_char,
 
     /// Information you wish to protect.
+    ///
+    /// It may have embedded NUL characters, thus it should be read by `content_len`.
     pub content: *mut c_char,
+
+    /// Length of `content`, in bytes and excluding the NUL terminator.
+    pub content_len: usize,
 }
 
 impl TryAssignFrom<senhasegura_rs::AccessProtectedInformationResult>
@@ -53,62 +72,163 @@ impl TryAssignFrom<senhasegura_rs::AccessProtectedInformationResult>
         value: senhasegura_rs::AccessProtectedInformationResult,
     ) -> Result<(), Error> {
         self.id = value.id;
+        self.tag = option_str_to_c_char(value.tag.as_deref())?;
+        self.r#type = option_str_to_c_char(value.r#type.as_deref())?;
+        (self.content, self.content_len) = str_to_c_char_with_len(&value.content);
 
-        if let Some(tag) = value.tag {
-            assign_str_to_c_char(&tag, self.tag)?;
-        }
-
-        if let Some(r#type) = value.r#type {
-            assign_str_to_c_char(&r#type, self.r#type)?;
-        }
+        Ok(())
+    }
+}
 
-        assign_str_to_c_char(&value.content, self.content)?;
+impl FreeStrings for AccessProtectedInformationResult {
+    fn free_strings(&mut self) {
+        free_c_char(&mut self.tag);
+        free_c_char(&mut self.r#type);
+        free_c_char_with_len(&mut self.content, &mut self.content_len);
+    }
+}
 
-        Ok(())
+impl Default for AccessProtectedInformationResult {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            tag: std::ptr::null_mut(),
+            r#type: std::ptr::null_mut(),
+            content: std::ptr::null_mut(),
+            content_len: 0,
+        }
     }
 }
 
 /// Access protected information.
 ///
+/// On success, `response` is filled with library-allocated strings, which must be freed with
+/// `free_access_protected_information_api_response`; on API errors, `error` is filled instead,
+/// and must be freed with `free_api_error`. Previous strings of either struct aren't freed.
+///
 /// @see https://docs.senhasegura.io/docs/a2a-pam-core-access-protected-information.
 ///
 /// # Safety
 ///
-/// - The `id` parameter must be a valid C-style string.
+/// - The `tag` field of the `id` parameter must be a valid C-style string, if its kind is `TAG`.
 /// - The `response` parameter must be a valid pointer to an `AccessProtectedInformationApiResponse`
 ///   struct.
 /// - The `error` parameter must be a valid pointer to an `ApiError` struct.
 #[no_mangle]
 pub unsafe extern "C" fn access_protected_information(
     client: *const SenhaseguraClient,
-    id: *const c_char,
+    id: ProtectedInformationIdentifier,
     response: *mut AccessProtectedInformationApiResponse,
     error: *mut ApiError,
 ) -> ErrorCode {
-    let client = match unsafe { client.as_ref() } {
-        Some(client) => client,
-        None => return ErrorCode::ClientNotInitialized,
-    };
-
-    let id = match c_char_to_string(id) {
-        Ok(id) => id,
-        Err(e) => return e.into(),
-    };
-
-    let response = match unsafe { response.as_mut() } {
-        Some(response) => response,
-        None => return ErrorCode::InvalidNullPointer,
-    };
-
-    let error = match unsafe { error.as_mut() } {
-        Some(error) => error,
-        None => return ErrorCode::InvalidNullPointer,
-    };
-
-    let result = client.0.access_protected_information_sync(id);
-
-    match handle_result(result, response, error) {
-        Ok(_) => ErrorCode::Ok,
-        Err(e) => e.into(),
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        let id = match senhasegura_rs::ProtectedInformationIdentifier::try_from(&id) {
+            Ok(id) => id,
+            Err(e) => return e.into(),
+        };
+
+        let response = match unsafe { response.as_mut() } {
+            Some(response) => response,
+            None => return Error::invalid_null_pointer("response").into(),
+        };
+
+        let error = match unsafe { error.as_mut() } {
+            Some(error) => error,
+            None => return Error::invalid_null_pointer("error").into(),
+        };
+
+        let result = match client.0.async_runtime() {
+            Ok(runtime) => runtime.block_on(client.0.access_protected_information(id)),
+            Err(e) => Err(e),
+        };
+
+        match handle_result(result, response, error) {
+            Ok(_) => ErrorCode::Ok,
+            Err(e) => e.into(),
+        }
+    })
+}
+
+/// Completion callback of `access_protected_information_async`.
+///
+/// The `response` parameter is only set on success, while the `error` parameter is only set on API
+/// errors. Both are owned by the library, and only valid until the callback returns.
+pub type AccessProtectedInformationCallback = extern "C" fn(
+    user_data: *mut c_void,
+    error_code: ErrorCode,
+    response: *const AccessProtectedInformationApiResponse,
+    error: *const ApiError,
+);
+
+/// Access protected information, asynchronously.
+///
+/// The request runs on the client's async runtime, then `callback` is invoked on one of its
+/// threads (thus it shouldn't block, nor call blocking functions) with `user_data`. Unless this
+/// function fails, the callback is invoked exactly once.
+///
+/// If the `request` parameter isn't null, it's set to a handle which may be used to cancel the
+/// request, and must be freed with `free_senhasegura_request`.
+///
+/// @see https://docs.senhasegura.io/docs/a2a-pam-core-access-protected-information.
+///
+/// # Safety
+///
+/// - The `tag` field of the `id` parameter must be a valid C-style string, if its kind is `TAG`.
+/// - The `request` parameter must be null, or a valid pointer to a `SenhaseguraRequest` pointer.
+#[no_mangle]
+pub unsafe extern "C" fn access_protected_information_async(
+    client: *const SenhaseguraClient,
+    id: ProtectedInformationIdentifier,
+    callback: Option<AccessProtectedInformationCallback>,
+    user_data: *mut c_void,
+    request: *mut *mut SenhaseguraRequest,
+) -> ErrorCode {
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        let id = match senhasegura_rs::ProtectedInformationIdentifier::try_from(&id) {
+            Ok(id) => id,
+            Err(e) => return e.into(),
+        };
+
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return Error::invalid_null_pointer("callback").into(),
+        };
+
+        let rs_client = client.0.clone();
+        let future = async move { rs_client.access_protected_information(id).await };
+
+        spawn_request::<_, AccessProtectedInformationApiResponse, _>(
+            client, request, user_data, future, callback,
+        )
+        .into()
+    })
+}
+
+/// Frees the strings of an access protected information API response, which was filled by the
+/// library.
+///
+/// The struct itself is owned by the caller, and may be reused afterwards.
+///
+/// # Safety
+///
+/// - The `response` parameter must be null, or a valid pointer to an
+///   `AccessProtectedInformationApiResponse` struct whose strings were allocated by the library
+///   (or are null).
+#[no_mangle]
+pub unsafe extern "C" fn free_access_protected_information_api_response(
+    response: *mut AccessProtectedInformationApiResponse,
+) {
+    if let Some(response) = unsafe { response.as_mut() } {
+        response.free_strings();
     }
 }
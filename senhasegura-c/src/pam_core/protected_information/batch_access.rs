@@ -0,0 +1,95 @@
+use crate::{
+    catch_panic, handle_result, AccessProtectedInformationApiResponse, ApiError, Error, ErrorCode,
+    ProtectedInformationIdentifier, SenhaseguraClient,
+};
+
+/// Access many protected information items at once, fetching at most `max_concurrency` of them
+/// concurrently.
+///
+/// Items are accessed independently: the result of the i-th item is stored in `error_codes[i]`,
+/// along with `responses[i]` (if successful) or `errors[i]` (if an API error occurred), which must
+/// be freed with `free_access_protected_information_api_response` or `free_api_error`. The
+/// function itself only fails if its arguments are invalid, while the last error message (see
+/// `senhasegura_last_error_message`) is the one of the last item which failed.
+///
+/// @see https://docs.senhasegura.io/docs/a2a-pam-core-access-protected-information.
+///
+/// # Safety
+///
+/// - The `ids` parameter must be a valid pointer to an array of `n_ids`
+///   `ProtectedInformationIdentifier` structs, whose tags are valid C-style strings.
+/// - The `responses` parameter must be a valid pointer to an array of `n_ids`
+///   `AccessProtectedInformationApiResponse` structs.
+/// - The `errors` parameter must be a valid pointer to an array of `n_ids` `ApiError` structs.
+/// - The `error_codes` parameter must be a valid pointer to an array of `n_ids` `ErrorCode`s.
+#[no_mangle]
+pub unsafe extern "C" fn access_protected_information_batch(
+    client: *const SenhaseguraClient,
+    ids: *const ProtectedInformationIdentifier,
+    n_ids: usize,
+    max_concurrency: usize,
+    responses: *mut AccessProtectedInformationApiResponse,
+    errors: *mut ApiError,
+    error_codes: *mut ErrorCode,
+) -> ErrorCode {
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        for (name, is_null) in [
+            ("ids", ids.is_null()),
+            ("responses", responses.is_null()),
+            ("errors", errors.is_null()),
+            ("error_codes", error_codes.is_null()),
+        ] {
+            if is_null {
+                return Error::invalid_null_pointer(name).into();
+            }
+        }
+
+        let ids = match unsafe { std::slice::from_raw_parts(ids, n_ids) }
+            .iter()
+            .map(senhasegura_rs::ProtectedInformationIdentifier::try_from)
+            .collect::<Result<Vec<_>, Error>>()
+        {
+            Ok(ids) => ids,
+            Err(e) => return e.into(),
+        };
+
+        let responses = unsafe { std::slice::from_raw_parts_mut(responses, n_ids) };
+        let errors = unsafe { std::slice::from_raw_parts_mut(errors, n_ids) };
+        let error_codes = unsafe { std::slice::from_raw_parts_mut(error_codes, n_ids) };
+
+        let results = match client
+            .0
+            .access_protected_information_batch_sync(ids.clone(), max_concurrency)
+        {
+            Ok(results) => results,
+            Err(e) => return Error::from(e).into(),
+        };
+
+        for (i, id) in ids.into_iter().enumerate() {
+            // Duplicate identifiers are only accessed once, thus results are cloned for each of them.
+            let result = match results.get(&id) {
+                Some(Ok(response)) => Ok(response.clone()),
+                Some(Err(senhasegura_rs::Error::Api(api_error))) => {
+                    Err(senhasegura_rs::Error::Api(api_error.clone()))
+                }
+                Some(Err(e)) => {
+                    error_codes[i] = Error::from(e).into();
+                    continue;
+                }
+                None => {
+                    error_codes[i] = Error::other("missing result").into();
+                    continue;
+                }
+            };
+
+            error_codes[i] = handle_result(result, &mut responses[i], &mut errors[i]).into();
+        }
+
+        ErrorCode::Ok
+    })
+}
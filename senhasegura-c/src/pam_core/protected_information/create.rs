@@ -1,8 +1,11 @@
-use std::ffi::c_char;
+use std::ffi::{c_char, c_void};
+
+use senhasegura_rs::CreateProtectedInformationApi;
 
 use crate::{
-    assign_str_to_c_char, c_char_to_string, handle_result, ApiError, Error, ErrorCode, Response,
-    SenhaseguraClient, TryAssignFrom,
+    c_char_to_string, catch_panic, free_c_char, free_c_char_with_len, handle_result,
+    option_str_to_c_char, spawn_request, str_to_c_char_with_len, ApiError, Error, ErrorCode,
+    FreeStrings, Response, SenhaseguraClient, SenhaseguraRequest, TryAssignFrom,
 };
 
 /// Create protected information API request.
@@ -30,21 +33,21 @@ impl TryFrom<&CreateProtectedInformationApiRequest>
         let name = if value.name.is_null() {
             None
         } else {
-            Some(c_char_to_string(value.name)?)
+            Some(c_char_to_string(value.name, "name")?)
         };
 
-        let content = c_char_to_string(value.content)?;
+        let content = c_char_to_string(value.content, "content")?;
 
         let identifier = if value.identifier.is_null() {
             None
         } else {
-            Some(c_char_to_string(value.identifier)?)
+            Some(c_char_to_string(value.identifier, "identifier")?)
         };
 
         let r#type = if value.r#type.is_null() {
             None
         } else {
-            Some(c_char_to_string(value.r#type)?)
+            Some(c_char_to_string(value.r#type, "type")?)
         };
 
         Ok(Self {
@@ -57,6 +60,10 @@ impl TryFrom<&CreateProtectedInformationApiRequest>
 }
 
 /// Create protected information API response.
+///
+/// Its strings are allocated by the library, and must be freed with
+/// `free_create_protected_information_api_response`.
+#[derive(Default)]
 #[repr(C)]
 pub struct CreateProtectedInformationApiResponse {
     /// Response.
@@ -80,6 +87,13 @@ impl TryAssignFrom<senhasegura_rs::CreateProtectedInformationApiResponse>
     }
 }
 
+impl FreeStrings for CreateProtectedInformationApiResponse {
+    fn free_strings(&mut self) {
+        self.response.free_strings();
+        self.info.free_strings();
+    }
+}
+
 /// Create protected information result (i.e. "info") field.
 #[repr(C)]
 pub struct CreateProtectedInformationResult {
@@ -96,8 +110,13 @@ pub struct CreateProtectedInformationResult {
     pub url: *mut c_char,
 
     /// Information you wish to protect.
+    ///
+    /// It may have embedded NUL characters, thus it should be read by `content_len`.
     pub content: *mut c_char,
 
+    /// Length of `content`, in bytes and excluding the NUL terminator.
+    pub content_len: usize,
+
     /// Comma-separated ACL of users / groups (optional).
     pub users_allowed: *mut c_char,
 
@@ -112,38 +131,51 @@ impl TryAssignFrom<senhasegura_rs::CreateProtectedInformationResult>
         &mut self,
         value: senhasegura_rs::CreateProtectedInformationResult,
     ) -> Result<(), Error> {
-        if let Some(name) = value.name {
-            assign_str_to_c_char(&name, self.name)?;
-        }
+        self.name = option_str_to_c_char(value.name.as_deref())?;
+        self.r#type = option_str_to_c_char(value.r#type.as_deref())?;
+        self.service = option_str_to_c_char(value.service.as_deref())?;
+        self.url = option_str_to_c_char(value.url.as_deref())?;
+        (self.content, self.content_len) = str_to_c_char_with_len(&value.content);
+        self.users_allowed = option_str_to_c_char(value.users_allowed.as_deref())?;
+        self.identifier = option_str_to_c_char(value.identifier.as_deref())?;
 
-        if let Some(r#type) = value.r#type {
-            assign_str_to_c_char(&r#type, self.r#type)?;
-        }
-
-        if let Some(service) = value.service {
-            assign_str_to_c_char(&service, self.service)?;
-        }
-
-        if let Some(url) = value.url {
-            assign_str_to_c_char(&url, self.url)?;
-        }
-
-        assign_str_to_c_char(&value.content, self.content)?;
+        Ok(())
+    }
+}
 
-        if let Some(users_allowed) = value.users_allowed {
-            assign_str_to_c_char(&users_allowed, self.users_allowed)?;
-        }
+impl FreeStrings for CreateProtectedInformationResult {
+    fn free_strings(&mut self) {
+        free_c_char(&mut self.name);
+        free_c_char(&mut self.r#type);
+        free_c_char(&mut self.service);
+        free_c_char(&mut self.url);
+        free_c_char_with_len(&mut self.content, &mut self.content_len);
+        free_c_char(&mut self.users_allowed);
+        free_c_char(&mut self.identifier);
+    }
+}
 
-        if let Some(identifier) = value.identifier {
-            assign_str_to_c_char(&identifier, self.identifier)?;
+impl Default for CreateProtectedInformationResult {
+    fn default() -> Self {
+        Self {
+            name: std::ptr::null_mut(),
+            r#type: std::ptr::null_mut(),
+            service: std::ptr::null_mut(),
+            url: std::ptr::null_mut(),
+            content: std::ptr::null_mut(),
+            content_len: 0,
+            users_allowed: std::ptr::null_mut(),
+            identifier: std::ptr::null_mut(),
         }
-
-        Ok(())
     }
 }
 
 /// Create / update protected information.
 ///
+/// On success, `response` is filled with library-allocated strings, which must be freed with
+/// `free_create_protected_information_api_response`; on API errors, `error` is filled instead,
+/// and must be freed with `free_api_error`. Previous strings of either struct aren't freed.
+///
 /// @see https://docs.senhasegura.io/docs/a2a-pam-core-create-protected-information.
 ///
 /// # Safety
@@ -160,37 +192,126 @@ pub unsafe extern "C" fn create_protected_information(
     response: *mut CreateProtectedInformationApiResponse,
     error: *mut ApiError,
 ) -> ErrorCode {
-    let client = match unsafe { client.as_ref() } {
-        Some(client) => client,
-        None => return ErrorCode::ClientNotInitialized,
-    };
-
-    let request = {
-        let request = match unsafe { request.as_ref() } {
-            Some(request) => request,
-            None => return ErrorCode::InvalidNullPointer,
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        let request = {
+            let request = match unsafe { request.as_ref() } {
+                Some(request) => request,
+                None => return Error::invalid_null_pointer("request").into(),
+            };
+
+            match senhasegura_rs::CreateProtectedInformationApiRequest::try_from(request) {
+                Ok(request) => request,
+                Err(e) => return e.into(),
+            }
         };
 
-        match senhasegura_rs::CreateProtectedInformationApiRequest::try_from(request) {
-            Ok(request) => request,
-            Err(e) => return e.into(),
+        let response = match unsafe { response.as_mut() } {
+            Some(response) => response,
+            None => return Error::invalid_null_pointer("response").into(),
+        };
+
+        let error = match unsafe { error.as_mut() } {
+            Some(error) => error,
+            None => return Error::invalid_null_pointer("error").into(),
+        };
+
+        let result = client.0.create_protected_information_sync(request);
+
+        match handle_result(result, response, error) {
+            Ok(_) => ErrorCode::Ok,
+            Err(e) => e.into(),
         }
-    };
+    })
+}
 
-    let response = match unsafe { response.as_mut() } {
-        Some(response) => response,
-        None => return ErrorCode::InvalidNullPointer,
-    };
+/// Completion callback of `create_protected_information_async`.
+///
+/// The `response` parameter is only set on success, while the `error` parameter is only set on API
+/// errors. Both are owned by the library, and only valid until the callback returns.
+pub type CreateProtectedInformationCallback = extern "C" fn(
+    user_data: *mut c_void,
+    error_code: ErrorCode,
+    response: *const CreateProtectedInformationApiResponse,
+    error: *const ApiError,
+);
+
+/// Create / update protected information, asynchronously.
+///
+/// The request runs on the client's async runtime, then `callback` is invoked on one of its
+/// threads (thus it shouldn't block, nor call blocking functions) with `user_data`. Unless this
+/// function fails, the callback is invoked exactly once.
+///
+/// If the `request` parameter isn't null, it's set to a handle which may be used to cancel the
+/// request, and must be freed with `free_senhasegura_request`.
+///
+/// @see https://docs.senhasegura.io/docs/a2a-pam-core-create-protected-information.
+///
+/// # Safety
+///
+/// - The `request_data` parameter must be a valid pointer to a
+///   `CreateProtectedInformationApiRequest` struct.
+/// - The `request` parameter must be null, or a valid pointer to a `SenhaseguraRequest` pointer.
+#[no_mangle]
+pub unsafe extern "C" fn create_protected_information_async(
+    client: *const SenhaseguraClient,
+    request_data: *const CreateProtectedInformationApiRequest,
+    callback: Option<CreateProtectedInformationCallback>,
+    user_data: *mut c_void,
+    request: *mut *mut SenhaseguraRequest,
+) -> ErrorCode {
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        let request_data = {
+            let request_data = match unsafe { request_data.as_ref() } {
+                Some(request_data) => request_data,
+                None => return Error::invalid_null_pointer("request_data").into(),
+            };
 
-    let error = match unsafe { error.as_mut() } {
-        Some(error) => error,
-        None => return ErrorCode::InvalidNullPointer,
-    };
+            match senhasegura_rs::CreateProtectedInformationApiRequest::try_from(request_data) {
+                Ok(request_data) => request_data,
+                Err(e) => return e.into(),
+            }
+        };
 
-    let result = client.0.create_protected_information_sync(request);
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return Error::invalid_null_pointer("callback").into(),
+        };
+
+        let rs_client = client.0.clone();
+        let future = async move { rs_client.create_protected_information(request_data).await };
+
+        spawn_request::<_, CreateProtectedInformationApiResponse, _>(
+            client, request, user_data, future, callback,
+        )
+        .into()
+    })
+}
 
-    match handle_result(result, response, error) {
-        Ok(_) => ErrorCode::Ok,
-        Err(e) => e.into(),
+/// Frees the strings of a create protected information API response, which was filled by the
+/// library.
+///
+/// The struct itself is owned by the caller, and may be reused afterwards.
+///
+/// # Safety
+///
+/// - The `response` parameter must be null, or a valid pointer to a
+///   `CreateProtectedInformationApiResponse` struct whose strings were allocated by the library
+///   (or are null).
+#[no_mangle]
+pub unsafe extern "C" fn free_create_protected_information_api_response(
+    response: *mut CreateProtectedInformationApiResponse,
+) {
+    if let Some(response) = unsafe { response.as_mut() } {
+        response.free_strings();
     }
 }
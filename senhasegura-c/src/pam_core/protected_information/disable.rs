@@ -1,11 +1,17 @@
-use std::ffi::c_char;
+use std::ffi::c_void;
+
+use senhasegura_rs::DisableProtectedInformationApi;
 
 use crate::{
-    c_char_to_string, handle_result, ApiError, Error, ErrorCode, Response, SenhaseguraClient,
-    TryAssignFrom,
+    catch_panic, handle_result, spawn_request, ApiError, Error, ErrorCode, FreeStrings,
+    ProtectedInformationIdentifier, Response, SenhaseguraClient, SenhaseguraRequest, TryAssignFrom,
 };
 
 /// Disable protected information API response.
+///
+/// Its strings are allocated by the library, and must be freed with
+/// `free_disable_protected_information_api_response`.
+#[derive(Default)]
 #[repr(C)]
 pub struct DisableProtectedInformationApiResponse {
     /// Response.
@@ -25,47 +31,141 @@ impl TryAssignFrom<senhasegura_rs::DisableProtectedInformationApiResponse>
     }
 }
 
+impl FreeStrings for DisableProtectedInformationApiResponse {
+    fn free_strings(&mut self) {
+        self.response.free_strings();
+    }
+}
+
 /// Disable protected information.
 ///
+/// On success, `response` is filled with library-allocated strings, which must be freed with
+/// `free_disable_protected_information_api_response`; on API errors, `error` is filled instead,
+/// and must be freed with `free_api_error`. Previous strings of either struct aren't freed.
+///
 /// @see https://docs.senhasegura.io/docs/a2a-pam-core-disable-protected-information.
 ///
 /// # Safety
 ///
-/// - The `id` parameter must be a valid C-style string.
+/// - The `tag` field of the `id` parameter must be a valid C-style string, if its kind is `TAG`.
 /// - The `response` parameter must be a valid pointer to an `DisableProtectedInformationApiResponse`
 ///   struct.
 /// - The `error` parameter must be a valid pointer to an `ApiError` struct.
 #[no_mangle]
 pub unsafe extern "C" fn disable_protected_information(
     client: *const SenhaseguraClient,
-    id: *const c_char,
+    id: ProtectedInformationIdentifier,
     response: *mut DisableProtectedInformationApiResponse,
     error: *mut ApiError,
 ) -> ErrorCode {
-    let client = match unsafe { client.as_ref() } {
-        Some(client) => client,
-        None => return ErrorCode::ClientNotInitialized,
-    };
-
-    let id = match c_char_to_string(id) {
-        Ok(id) => id,
-        Err(e) => return e.into(),
-    };
-
-    let response = match unsafe { response.as_mut() } {
-        Some(response) => response,
-        None => return ErrorCode::InvalidNullPointer,
-    };
-
-    let error = match unsafe { error.as_mut() } {
-        Some(error) => error,
-        None => return ErrorCode::InvalidNullPointer,
-    };
-
-    let result = client.0.disable_protected_information_sync(id);
-
-    match handle_result(result, response, error) {
-        Ok(_) => ErrorCode::Ok,
-        Err(e) => e.into(),
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        let id = match senhasegura_rs::ProtectedInformationIdentifier::try_from(&id) {
+            Ok(id) => id,
+            Err(e) => return e.into(),
+        };
+
+        let response = match unsafe { response.as_mut() } {
+            Some(response) => response,
+            None => return Error::invalid_null_pointer("response").into(),
+        };
+
+        let error = match unsafe { error.as_mut() } {
+            Some(error) => error,
+            None => return Error::invalid_null_pointer("error").into(),
+        };
+
+        let result = match client.0.async_runtime() {
+            Ok(runtime) => runtime.block_on(client.0.disable_protected_information(id)),
+            Err(e) => Err(e),
+        };
+
+        match handle_result(result, response, error) {
+            Ok(_) => ErrorCode::Ok,
+            Err(e) => e.into(),
+        }
+    })
+}
+
+/// Completion callback of `disable_protected_information_async`.
+///
+/// The `response` parameter is only set on success, while the `error` parameter is only set on API
+/// errors. Both are owned by the library, and only valid until the callback returns.
+pub type DisableProtectedInformationCallback = extern "C" fn(
+    user_data: *mut c_void,
+    error_code: ErrorCode,
+    response: *const DisableProtectedInformationApiResponse,
+    error: *const ApiError,
+);
+
+/// Disable protected information, asynchronously.
+///
+/// The request runs on the client's async runtime, then `callback` is invoked on one of its
+/// threads (thus it shouldn't block, nor call blocking functions) with `user_data`. Unless this
+/// function fails, the callback is invoked exactly once.
+///
+/// If the `request` parameter isn't null, it's set to a handle which may be used to cancel the
+/// request, and must be freed with `free_senhasegura_request`.
+///
+/// @see https://docs.senhasegura.io/docs/a2a-pam-core-disable-protected-information.
+///
+/// # Safety
+///
+/// - The `tag` field of the `id` parameter must be a valid C-style string, if its kind is `TAG`.
+/// - The `request` parameter must be null, or a valid pointer to a `SenhaseguraRequest` pointer.
+#[no_mangle]
+pub unsafe extern "C" fn disable_protected_information_async(
+    client: *const SenhaseguraClient,
+    id: ProtectedInformationIdentifier,
+    callback: Option<DisableProtectedInformationCallback>,
+    user_data: *mut c_void,
+    request: *mut *mut SenhaseguraRequest,
+) -> ErrorCode {
+    catch_panic(|| {
+        let client = match unsafe { client.as_ref() } {
+            Some(client) => client,
+            None => return Error::client_not_initialized().into(),
+        };
+
+        let id = match senhasegura_rs::ProtectedInformationIdentifier::try_from(&id) {
+            Ok(id) => id,
+            Err(e) => return e.into(),
+        };
+
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return Error::invalid_null_pointer("callback").into(),
+        };
+
+        let rs_client = client.0.clone();
+        let future = async move { rs_client.disable_protected_information(id).await };
+
+        spawn_request::<_, DisableProtectedInformationApiResponse, _>(
+            client, request, user_data, future, callback,
+        )
+        .into()
+    })
+}
+
+/// Frees the strings of a disable protected information API response, which was filled by the
+/// library.
+///
+/// The struct itself is owned by the caller, and may be reused afterwards.
+///
+/// # Safety
+///
+/// - The `response` parameter must be null, or a valid pointer to a
+///   `DisableProtectedInformationApiResponse` struct whose strings were allocated by the library
+///   (or are null).
+#[no_mangle]
+pub unsafe extern "C" fn free_disable_protected_information_api_response(
+    response: *mut DisableProtectedInformationApiResponse,
+) {
+    if let Some(response) = unsafe { response.as_mut() } {
+        response.free_strings();
     }
 }
@@ -1,8 +1,71 @@
 mod access;
 pub use access::*;
 
+mod batch_access;
+pub use batch_access::*;
+
 mod create;
 pub use create::*;
 
 mod disable;
 pub use disable::*;
+
+use std::ffi::c_char;
+
+use crate::{c_char_to_string, Error};
+
+/// Kind of a protected information identifier.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub enum ProtectedInformationIdentifierKind {
+    /// Protected information item’s unique identification code.
+    Id = 0,
+
+    /// Information identifier (i.e. tag).
+    Tag = 1,
+}
+
+impl TryFrom<u32> for ProtectedInformationIdentifierKind {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Id),
+            1 => Ok(Self::Tag),
+            _ => Err(Error::invalid_argument(format!(
+                "unknown identifier kind: {value}"
+            ))),
+        }
+    }
+}
+
+/// Protected information identifier, i.e. either its ID or its tag.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ProtectedInformationIdentifier {
+    /// Kind of the identifier (see `ProtectedInformationIdentifierKind`), which selects the field
+    /// to use.
+    ///
+    /// It's an integer rather than the enum, so that unknown values are rejected with
+    /// `INVALID_ARGUMENT` instead of being undefined behavior.
+    pub kind: u32,
+
+    /// Protected information item’s unique identification code (if `kind` is `ID`).
+    pub id: i32,
+
+    /// Information identifier (if `kind` is `TAG`).
+    pub tag: *const c_char,
+}
+
+impl TryFrom<&ProtectedInformationIdentifier> for senhasegura_rs::ProtectedInformationIdentifier {
+    type Error = Error;
+
+    fn try_from(value: &ProtectedInformationIdentifier) -> Result<Self, Self::Error> {
+        match ProtectedInformationIdentifierKind::try_from(value.kind)? {
+            ProtectedInformationIdentifierKind::Id => Ok(Self::Id(value.id)),
+            ProtectedInformationIdentifierKind::Tag => {
+                Ok(Self::Tag(c_char_to_string(value.tag, "tag")?))
+            }
+        }
+    }
+}
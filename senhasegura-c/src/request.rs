@@ -0,0 +1,117 @@
+use std::{ffi::c_void, future::Future, sync::Arc};
+
+use tokio::sync::Notify;
+
+use crate::{
+    catch_panic, handle_result, ApiError, Error, ErrorCode, FreeStrings, SenhaseguraClient,
+    TryAssignFrom,
+};
+
+/// Asynchronous request opaque struct, which may be used to cancel it.
+pub struct SenhaseguraRequest {
+    cancelled: Arc<Notify>,
+}
+
+/// Cancels the asynchronous request.
+///
+/// If the request didn't complete yet, its callback is invoked with `CANCELLED`; otherwise, this
+/// is a no-op. Either way, the request must still be freed with `free_senhasegura_request`.
+///
+/// # Safety
+///
+/// - The `request` parameter must be null, or a valid pointer to a `SenhaseguraRequest` struct.
+#[no_mangle]
+pub unsafe extern "C" fn cancel_senhasegura_request(request: *const SenhaseguraRequest) {
+    if let Some(request) = unsafe { request.as_ref() } {
+        request.cancelled.notify_one();
+    }
+}
+
+/// Frees the asynchronous request, without cancelling it.
+///
+/// # Safety
+///
+/// - The `request` parameter must be null, or a valid pointer to a `SenhaseguraRequest` struct
+///   which wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn free_senhasegura_request(request: *mut SenhaseguraRequest) {
+    if !request.is_null() {
+        drop(unsafe { Box::from_raw(request) });
+    }
+}
+
+/// Opaque pointer passed back to callbacks.
+struct UserData(*mut c_void);
+
+// The library never dereferences it, and the caller is responsible for its thread safety.
+unsafe impl Send for UserData {}
+
+impl UserData {
+    fn get(&self) -> *mut c_void {
+        self.0
+    }
+}
+
+/// Spawns the request on the client's async runtime, then invokes the callback on completion (or
+/// cancellation) with the response (if successful) or the API error (if one occurred), whose
+/// strings are freed once the callback returns.
+///
+/// The request handle is only stored if the `request` parameter isn't null.
+pub(crate) fn spawn_request<TR, TC, F>(
+    client: &SenhaseguraClient,
+    request: *mut *mut SenhaseguraRequest,
+    user_data: *mut c_void,
+    future: F,
+    callback: extern "C" fn(*mut c_void, ErrorCode, *const TC, *const ApiError),
+) -> Result<(), Error>
+where
+    TR: Send + 'static,
+    TC: TryAssignFrom<TR> + FreeStrings + Default + 'static,
+    F: Future<Output = Result<TR, senhasegura_rs::Error>> + Send + 'static,
+{
+    let runtime = client.0.async_runtime()?;
+
+    let cancelled = Arc::new(Notify::new());
+
+    if !request.is_null() {
+        let handle = Box::new(SenhaseguraRequest {
+            cancelled: cancelled.clone(),
+        });
+
+        unsafe { *request = Box::into_raw(handle) };
+    }
+
+    let user_data = UserData(user_data);
+
+    runtime.spawn(async move {
+        let result = tokio::select! {
+            biased;
+            _ = cancelled.notified() => None,
+            result = future => Some(result),
+        };
+
+        let mut response = TC::default();
+        let mut error = ApiError::default();
+
+        let error_code = catch_panic(|| match result {
+            Some(result) => handle_result(result, &mut response, &mut error).into(),
+            None => Error::cancelled().into(),
+        });
+
+        let response_ptr = match error_code {
+            ErrorCode::Ok => &response as *const TC,
+            _ => std::ptr::null(),
+        };
+        let error_ptr = match error_code {
+            ErrorCode::Api => &error as *const ApiError,
+            _ => std::ptr::null(),
+        };
+
+        callback(user_data.get(), error_code, response_ptr, error_ptr);
+
+        response.free_strings();
+        error.free_strings();
+    });
+
+    Ok(())
+}
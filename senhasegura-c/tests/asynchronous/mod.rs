@@ -0,0 +1,142 @@
+use std::{
+    ffi::{c_void, CStr},
+    sync::mpsc,
+    time::Duration,
+};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_c::{
+    access_protected_information_async, cancel_senhasegura_request, free_senhasegura_request,
+    AccessProtectedInformationApiResponse, ApiError, ErrorCode,
+};
+
+use crate::fixture::{self, Fixture};
+
+/// Outcome of a request, as received by its callback.
+type Outcome = (String, Option<String>);
+
+fn user_data(sender: &mpsc::Sender<Outcome>) -> *mut c_void {
+    Box::into_raw(Box::new(sender.clone())) as *mut c_void
+}
+
+extern "C" fn on_access(
+    user_data: *mut c_void,
+    error_code: ErrorCode,
+    response: *const AccessProtectedInformationApiResponse,
+    error: *const ApiError,
+) {
+    // The callback is invoked exactly once, thus it owns the sender.
+    let sender = unsafe { Box::from_raw(user_data as *mut mpsc::Sender<Outcome>) };
+
+    let detail = match (unsafe { response.as_ref() }, unsafe { error.as_ref() }) {
+        (Some(response), _) => Some(unsafe { CStr::from_ptr(response.info.content) }),
+        (None, Some(error)) => Some(unsafe { CStr::from_ptr(error.exception.message) }),
+        (None, None) => None,
+    };
+
+    let detail = detail.map(|detail| detail.to_str().unwrap().to_string());
+
+    sender.send((format!("{error_code:?}"), detail)).unwrap();
+}
+
+fn mount_info(fixture: &Fixture, delay: Duration) {
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path("/iso/pam/info/28"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({
+                        "response": {
+                            "status": 200,
+                            "message": "Information 28",
+                            "error": false,
+                            "error_code": 0
+                        },
+                        "info": {
+                            "id": "28",
+                            "tag": null,
+                            "type": "Access credential",
+                            "content": "password"
+                        }
+                    }))
+                    .set_delay(delay),
+            ),
+    );
+}
+
+#[test]
+fn test_access_protected_information_async() {
+    let fixture = Fixture::start();
+
+    mount_info(&fixture, Duration::ZERO);
+
+    let (sender, receiver) = mpsc::channel::<Outcome>();
+
+    let err = unsafe {
+        access_protected_information_async(
+            fixture.client(),
+            fixture::id(28),
+            Some(on_access),
+            user_data(&sender),
+            std::ptr::null_mut(),
+        )
+    };
+    assert!(matches!(err, ErrorCode::Ok));
+
+    let outcome = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(outcome, ("Ok".to_string(), Some("password".to_string())));
+}
+
+#[test]
+fn test_access_protected_information_async_cancel() {
+    let fixture = Fixture::start();
+
+    mount_info(&fixture, Duration::from_secs(30));
+
+    let (sender, receiver) = mpsc::channel::<Outcome>();
+
+    let mut request = std::ptr::null_mut();
+    let err = unsafe {
+        access_protected_information_async(
+            fixture.client(),
+            fixture::id(28),
+            Some(on_access),
+            user_data(&sender),
+            &mut request,
+        )
+    };
+    assert!(matches!(err, ErrorCode::Ok));
+    assert!(!request.is_null());
+
+    unsafe { cancel_senhasegura_request(request) };
+
+    let outcome = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(outcome, ("Cancelled".to_string(), None));
+
+    // The callback is invoked exactly once, even if cancelled again.
+    unsafe { cancel_senhasegura_request(request) };
+    assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+
+    unsafe { free_senhasegura_request(request) };
+}
+
+#[test]
+fn test_access_protected_information_async_invalid() {
+    let fixture = Fixture::start();
+
+    let err = unsafe {
+        access_protected_information_async(
+            fixture.client(),
+            fixture::id(28),
+            None,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    assert!(matches!(err, ErrorCode::InvalidNullPointer));
+}
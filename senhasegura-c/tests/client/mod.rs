@@ -0,0 +1,114 @@
+use std::ffi::{CStr, CString};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_c::{
+    access_protected_information, create_senhasegura_client, destroy_senhasegura_client,
+    free_api_error, senhasegura_last_error_message, AccessProtectedInformationApiResponse,
+    ApiError, ErrorCode, SenhaseguraClient, SenhaseguraClientProps,
+    SENHASEGURA_DEFAULT_MAX_N_RETRIES,
+};
+
+use crate::fixture::{self, Fixture};
+
+fn create(base_url: &str) -> (ErrorCode, *mut SenhaseguraClient) {
+    let base_url = CString::new(base_url).unwrap();
+    let client_id = CString::new("client_id").unwrap();
+    let client_secret = CString::new("client_secret").unwrap();
+
+    // Zeroed options (or the sentinel, for the maximum number of retries) mean their defaults.
+    let mut props = SenhaseguraClientProps {
+        base_url: base_url.as_ptr(),
+        request_timeout: 0,
+        client_id: client_id.as_ptr(),
+        client_secret: client_secret.as_ptr(),
+        base_retry_delay_secs: 0,
+        max_n_retries: SENHASEGURA_DEFAULT_MAX_N_RETRIES,
+    };
+
+    let mut client = std::ptr::null_mut();
+    let err = unsafe { create_senhasegura_client(&mut client, &mut props) };
+
+    (err, client)
+}
+
+#[test]
+fn test_create_senhasegura_client() {
+    let (err, client) = create("http://localhost:5000");
+    assert!(matches!(err, ErrorCode::Ok));
+    assert!(!client.is_null());
+
+    unsafe { destroy_senhasegura_client(client) };
+
+    // Destroying a null client is a no-op.
+    unsafe { destroy_senhasegura_client(std::ptr::null_mut()) };
+}
+
+#[test]
+fn test_create_senhasegura_client_invalid() {
+    let (err, client) = create("not a URL");
+    assert!(matches!(err, ErrorCode::InvalidUrl));
+    assert!(client.is_null());
+
+    let message = unsafe { CStr::from_ptr(senhasegura_last_error_message()) };
+    assert!(message.to_str().unwrap().starts_with("invalid URL: "));
+
+    let mut client = std::ptr::null_mut();
+    let err = unsafe { create_senhasegura_client(&mut client, std::ptr::null_mut()) };
+    assert!(matches!(err, ErrorCode::InvalidNullPointer));
+
+    let err = unsafe { create_senhasegura_client(std::ptr::null_mut(), std::ptr::null_mut()) };
+    assert!(matches!(err, ErrorCode::InvalidNullPointer));
+}
+
+#[test]
+fn test_create_senhasegura_client_no_retries() {
+    let fixture = Fixture::start();
+
+    // Server errors would be retried, unless retries are disabled.
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path("/iso/pam/info/28"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+                "response": {
+                    "status": 503,
+                    "message": "Service unavailable",
+                    "error": true,
+                    "error_code": 503
+                },
+                "exception": null
+            })))
+            .expect(1),
+    );
+
+    let base_url = CString::new(fixture.uri()).unwrap();
+    let client_id = CString::new("client_id").unwrap();
+    let client_secret = CString::new("client_secret").unwrap();
+
+    let mut props = SenhaseguraClientProps {
+        base_url: base_url.as_ptr(),
+        request_timeout: 10,
+        client_id: client_id.as_ptr(),
+        client_secret: client_secret.as_ptr(),
+        base_retry_delay_secs: 0,
+        max_n_retries: 0,
+    };
+
+    let mut client = std::ptr::null_mut();
+    let err = unsafe { create_senhasegura_client(&mut client, &mut props) };
+    assert!(matches!(err, ErrorCode::Ok));
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err =
+        unsafe { access_protected_information(client, fixture::id(28), &mut response, &mut error) };
+    assert!(matches!(err, ErrorCode::Api));
+
+    unsafe { free_api_error(&mut error) };
+    unsafe { destroy_senhasegura_client(client) };
+}
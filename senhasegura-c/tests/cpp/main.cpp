@@ -0,0 +1,65 @@
+// Example of the C++ wrapper, which is compiled and run against a mock server by the tests.
+
+#include <cstdlib>
+#include <iostream>
+
+#include "senhasegura.hpp"
+
+int main(int argc, char *argv[]) {
+    if (argc < 2) {
+        std::cerr << "usage: " << argv[0] << " <base_url>" << std::endl;
+        return EXIT_FAILURE;
+    }
+
+    senhasegura::ClientProps props;
+    props.base_url = argv[1];
+    props.client_id = "client_id";
+    props.client_secret = "client_secret";
+
+    try {
+        senhasegura::Client invalid({"not a URL", "client_id", "client_secret"});
+    } catch (const senhasegura::Error &e) {
+        std::cout << "invalid URL: " << (e.code() == INVALID_URL) << std::endl;
+    }
+
+    senhasegura::Client client(props);
+
+    // Clients may be moved, e.g. into containers.
+    senhasegura::Client moved = std::move(client);
+
+    auto info = moved.access_protected_information(28).info;
+    std::cout << "access by ID: " << info.content << " (" << info.tag.value_or("-") << ")"
+              << std::endl;
+
+    info = moved.access_protected_information("db-prod").info;
+    std::cout << "access by tag: " << info.content << " (" << info.id << ")" << std::endl;
+
+    try {
+        moved.access_protected_information(29);
+    } catch (const senhasegura::Error &e) {
+        const auto &api_error = e.api_error();
+
+        std::cout << "API error: " << (e.code() == API) << " " << api_error->response.status << " "
+                  << api_error->exception->code << " " << e.what() << std::endl;
+    }
+
+    senhasegura::CreateProtectedInformationApiRequest request;
+    request.name = "saas_vault1";
+    request.content = "login: mt4adm, password: mt4admp4ss";
+
+    auto created = moved.create_protected_information(request).info;
+    std::cout << "create: " << created.identifier.value_or("-") << " "
+              << created.service.value_or("-") << std::endl;
+
+    auto disabled = moved.disable_protected_information(28);
+    std::cout << "disable: " << disabled.response.message << std::endl;
+
+    // Calls on moved-from clients fail, instead of crashing.
+    try {
+        client.access_protected_information(28);
+    } catch (const senhasegura::Error &e) {
+        std::cout << "moved from: " << (e.code() == CLIENT_NOT_INITIALIZED) << std::endl;
+    }
+
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,180 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use crate::fixture::Fixture;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn info(id: &str, content: &str) -> Mock {
+    Mock::given(method("GET"))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information 28",
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": "28",
+                "tag": "db-prod",
+                "type": "Access credential",
+                "content": content
+            }
+        })))
+}
+
+fn mount(fixture: &Fixture) {
+    fixture.mount(info("28", "password"));
+    fixture.mount(info("db-prod", "password"));
+
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path("/iso/pam/info/29"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "response": {
+                    "status": 404,
+                    "message": "1023: Information not found",
+                    "error": true,
+                    "error_code": 1023
+                },
+                "exception": {
+                    "code": 1023,
+                    "message": "1023: Information not found",
+                    "detail": null
+                }
+            }))),
+    );
+
+    fixture.mount(
+        Mock::given(method("POST"))
+            .and(path("/iso/pam/info"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "response": {
+                    "status": 201,
+                    "message": "Information successfully registered!",
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "name": "saas_vault1",
+                    "type": "access credential",
+                    "service": "saas_client",
+                    "url": "10.10.10.2",
+                    "content": "login: mt4adm, password: mt4admp4ss",
+                    "users_allowed": "admin, account_manager, mscharra",
+                    "identifier": "INFOSAASVAULT1"
+                }
+            }))),
+    );
+
+    fixture.mount(
+        Mock::given(method("DELETE"))
+            .and(path("/iso/pam/info/28"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "response": {
+                    "status": 200,
+                    "message": "Information successfully disabled",
+                    "error": false,
+                    "error_code": 0
+                }
+            }))),
+    );
+}
+
+/// Generates the C header, as documented in the README.
+fn generate_header(dir: &Path) {
+    let config =
+        cbindgen::Config::from_file(Path::new(MANIFEST_DIR).join("cbindgen.toml")).unwrap();
+
+    cbindgen::Builder::new()
+        .with_crate(MANIFEST_DIR)
+        .with_config(config)
+        .generate()
+        .unwrap()
+        .write_to_file(dir.join("senhasegura_c.h"));
+}
+
+/// Compiles the example with the C++ compiler (i.e. `$CXX`, or `c++` by default), linking it
+/// against the library built for the tests.
+fn compile_example(dir: &Path) -> PathBuf {
+    // The library is built next to the test executable.
+    let lib_dir = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf();
+
+    let output = dir.join("example");
+
+    let mut command = Command::new(std::env::var("CXX").unwrap_or_else(|_| "c++".to_string()));
+
+    // The library is instrumented when tested under AddressSanitizer, thus the example must be too.
+    if std::env::var("RUSTFLAGS").is_ok_and(|flags| flags.contains("sanitizer=address")) {
+        command.arg("-fsanitize=address");
+    }
+
+    let status = command
+        .args(["-std=c++17", "-Wall", "-Wextra", "-Werror"])
+        .arg("-I")
+        .arg(Path::new(MANIFEST_DIR).join("include"))
+        .arg("-I")
+        .arg(dir)
+        .arg(Path::new(MANIFEST_DIR).join("tests/cpp/main.cpp"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-lsenhasegura_c")
+        .arg("-o")
+        .arg(&output)
+        .status()
+        .expect("a C++ compiler is required");
+    assert!(status.success());
+
+    output
+}
+
+#[test]
+fn test_cpp_example() {
+    let fixture = Fixture::start();
+
+    mount(&fixture);
+
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cpp");
+    std::fs::create_dir_all(&dir).unwrap();
+
+    generate_header(&dir);
+
+    let example = compile_example(&dir);
+
+    // Cargo adds its own directories to the search path, which might have stale builds of the library.
+    let output = Command::new(example)
+        .arg(fixture.uri())
+        .env_remove("LD_LIBRARY_PATH")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "invalid URL: 1\n\
+         access by ID: password (db-prod)\n\
+         access by tag: password (28)\n\
+         API error: 1 404 1023 1023: Information not found\n\
+         create: INFOSAASVAULT1 saas_client\n\
+         disable: Information successfully disabled\n\
+         moved from: 1\n"
+    );
+}
@@ -0,0 +1,92 @@
+use std::ffi::CString;
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use senhasegura_c::{
+    create_senhasegura_client, destroy_senhasegura_client, ErrorCode,
+    ProtectedInformationIdentifier, ProtectedInformationIdentifierKind, SenhaseguraClient,
+    SenhaseguraClientProps,
+};
+
+/// Returns the identifier of the protected information item with the ID.
+pub fn id(id: i32) -> ProtectedInformationIdentifier {
+    ProtectedInformationIdentifier {
+        kind: ProtectedInformationIdentifierKind::Id as u32,
+        id,
+        tag: std::ptr::null(),
+    }
+}
+
+/// Mock server and client, created through the FFI.
+///
+/// The FFI is blocking, thus the mock server runs on its own runtime, while tests call the FFI
+/// from the test thread.
+pub struct Fixture {
+    runtime: tokio::runtime::Runtime,
+    server: MockServer,
+    client: *mut SenhaseguraClient,
+}
+
+impl Fixture {
+    pub fn start() -> Self {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let server = runtime.block_on(MockServer::start());
+
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/iso/oauth2/token"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "access_token": "access_token",
+                    "token_type": "bearer",
+                    "expires_in": 3600
+                })))
+                .mount(&server),
+        );
+
+        let base_url = CString::new(server.uri()).unwrap();
+        let client_id = CString::new("client_id").unwrap();
+        let client_secret = CString::new("client_secret").unwrap();
+
+        let mut props = SenhaseguraClientProps {
+            base_url: base_url.as_ptr(),
+            request_timeout: 10,
+            client_id: client_id.as_ptr(),
+            client_secret: client_secret.as_ptr(),
+            base_retry_delay_secs: 0,
+            max_n_retries: 0,
+        };
+
+        let mut client = std::ptr::null_mut();
+        let err = unsafe { create_senhasegura_client(&mut client, &mut props) };
+        assert!(matches!(err, ErrorCode::Ok));
+
+        Self {
+            runtime,
+            server,
+            client,
+        }
+    }
+
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    pub fn client(&self) -> *const SenhaseguraClient {
+        self.client
+    }
+
+    pub fn mount(&self, mock: Mock) {
+        self.runtime.block_on(mock.mount(&self.server));
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        unsafe { destroy_senhasegura_client(self.client) };
+    }
+}
@@ -0,0 +1,169 @@
+use std::ffi::{CStr, CString};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_c::{
+    access_protected_information, access_protected_information_batch,
+    disable_protected_information, free_access_protected_information_api_response,
+    free_disable_protected_information_api_response, AccessProtectedInformationApiResponse,
+    ApiError, DisableProtectedInformationApiResponse, ErrorCode, ProtectedInformationIdentifier,
+    ProtectedInformationIdentifierKind,
+};
+
+use crate::fixture::{self, Fixture};
+
+fn tag(tag: &CString) -> ProtectedInformationIdentifier {
+    ProtectedInformationIdentifier {
+        kind: ProtectedInformationIdentifierKind::Tag as u32,
+        id: 0,
+        tag: tag.as_ptr(),
+    }
+}
+
+fn mount_info(fixture: &Fixture, id: &str, content: &str) {
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path(format!("/iso/pam/info/{id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "response": {
+                    "status": 200,
+                    "message": "Information 28",
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "id": "28",
+                    "tag": "db-prod",
+                    "type": "Access credential",
+                    "content": content
+                }
+            }))),
+    );
+}
+
+#[test]
+fn test_access_protected_information_by_tag() {
+    let fixture = Fixture::start();
+
+    mount_info(&fixture, "db-prod", "password");
+
+    let db_prod = CString::new("db-prod").unwrap();
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = unsafe {
+        access_protected_information(fixture.client(), tag(&db_prod), &mut response, &mut error)
+    };
+    assert!(matches!(err, ErrorCode::Ok));
+
+    assert_eq!(response.info.id, 28);
+    assert_eq!(
+        unsafe { CStr::from_ptr(response.info.tag) },
+        db_prod.as_c_str()
+    );
+
+    unsafe { free_access_protected_information_api_response(&mut response) };
+
+    // Tags are required for identifiers of the `TAG` kind.
+    let null_tag = ProtectedInformationIdentifier {
+        kind: ProtectedInformationIdentifierKind::Tag as u32,
+        id: 28,
+        tag: std::ptr::null(),
+    };
+
+    let err = unsafe {
+        access_protected_information(fixture.client(), null_tag, &mut response, &mut error)
+    };
+    assert!(matches!(err, ErrorCode::InvalidNullPointer));
+
+    // Unknown kinds are rejected, rather than read as an enum.
+    let unknown_kind = ProtectedInformationIdentifier {
+        kind: 7,
+        id: 28,
+        tag: db_prod.as_ptr(),
+    };
+
+    let err = unsafe {
+        access_protected_information(fixture.client(), unknown_kind, &mut response, &mut error)
+    };
+    assert!(matches!(err, ErrorCode::InvalidArgument));
+}
+
+#[test]
+fn test_disable_protected_information_by_tag() {
+    let fixture = Fixture::start();
+
+    fixture.mount(
+        Mock::given(method("DELETE"))
+            .and(path("/iso/pam/info/db-prod"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "response": {
+                    "status": 200,
+                    "message": "Information disabled",
+                    "error": false,
+                    "error_code": 0
+                }
+            })))
+            .expect(1),
+    );
+
+    let db_prod = CString::new("db-prod").unwrap();
+
+    let mut response = DisableProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = unsafe {
+        disable_protected_information(fixture.client(), tag(&db_prod), &mut response, &mut error)
+    };
+    assert!(matches!(err, ErrorCode::Ok));
+
+    unsafe { free_disable_protected_information_api_response(&mut response) };
+}
+
+#[test]
+fn test_access_protected_information_batch_by_id_and_tag() {
+    let fixture = Fixture::start();
+
+    mount_info(&fixture, "28", "by id");
+    mount_info(&fixture, "db-prod", "by tag");
+
+    let db_prod = CString::new("db-prod").unwrap();
+    let ids = [fixture::id(28), tag(&db_prod)];
+
+    let mut responses = [
+        AccessProtectedInformationApiResponse::default(),
+        AccessProtectedInformationApiResponse::default(),
+    ];
+    let mut errors = [ApiError::default(), ApiError::default()];
+    let mut error_codes = [ErrorCode::Other, ErrorCode::Other];
+
+    let err = unsafe {
+        access_protected_information_batch(
+            fixture.client(),
+            ids.as_ptr(),
+            ids.len(),
+            2,
+            responses.as_mut_ptr(),
+            errors.as_mut_ptr(),
+            error_codes.as_mut_ptr(),
+        )
+    };
+    assert!(matches!(err, ErrorCode::Ok));
+    assert!(matches!(error_codes, [ErrorCode::Ok, ErrorCode::Ok]));
+
+    for (response, content) in responses.iter_mut().zip(["by id", "by tag"]) {
+        assert_eq!(
+            unsafe { CStr::from_ptr(response.info.content) }
+                .to_str()
+                .unwrap(),
+            content
+        );
+
+        unsafe { free_access_protected_information_api_response(response) };
+    }
+}
@@ -0,0 +1,129 @@
+use std::ffi::{CStr, CString};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_c::{
+    access_protected_information, create_senhasegura_client, destroy_senhasegura_client,
+    free_api_error, senhasegura_last_error_length, senhasegura_last_error_message,
+    AccessProtectedInformationApiResponse, ApiError, ErrorCode, SenhaseguraClientProps,
+};
+
+use crate::fixture::{self, Fixture};
+
+fn last_error_message() -> Option<String> {
+    let message = senhasegura_last_error_message();
+    if message.is_null() {
+        return None;
+    }
+
+    let message = unsafe { CStr::from_ptr(message) }.to_str().unwrap();
+    assert_eq!(message.len(), senhasegura_last_error_length());
+
+    Some(message.to_string())
+}
+
+#[test]
+fn test_last_error_invalid_argument() {
+    let fixture = Fixture::start();
+
+    let mut error = ApiError::default();
+
+    let err = unsafe {
+        access_protected_information(
+            fixture.client(),
+            fixture::id(28),
+            std::ptr::null_mut(),
+            &mut error,
+        )
+    };
+    assert!(matches!(err, ErrorCode::InvalidNullPointer));
+
+    assert_eq!(
+        last_error_message().as_deref(),
+        Some("invalid null pointer: `response`")
+    );
+
+    // The last error is stored per thread.
+    std::thread::spawn(|| assert_eq!(last_error_message(), None))
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_last_error_api() {
+    let fixture = Fixture::start();
+
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path("/iso/pam/info/28"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "response": {
+                    "status": 400,
+                    "message": "1023: Information not found",
+                    "error": true,
+                    "error_code": 1023
+                },
+                "exception": {
+                    "code": 1023,
+                    "message": "1023: Information not found",
+                    "detail": null
+                }
+            }))),
+    );
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = unsafe {
+        access_protected_information(fixture.client(), fixture::id(28), &mut response, &mut error)
+    };
+    assert!(matches!(err, ErrorCode::Api));
+
+    assert_eq!(
+        last_error_message().as_deref(),
+        Some("1023: Information not found")
+    );
+
+    unsafe { free_api_error(&mut error) };
+}
+
+#[test]
+fn test_last_error_transport() {
+    // Nothing listens on the discard port, thus connections are refused.
+    let base_url = CString::new("http://127.0.0.1:9").unwrap();
+    let client_id = CString::new("client_id").unwrap();
+    let client_secret = CString::new("client_secret").unwrap();
+
+    let mut client = std::ptr::null_mut();
+    let err = unsafe {
+        create_senhasegura_client(
+            &mut client,
+            &mut SenhaseguraClientProps {
+                base_url: base_url.as_ptr(),
+                request_timeout: 10,
+                client_id: client_id.as_ptr(),
+                client_secret: client_secret.as_ptr(),
+                base_retry_delay_secs: 1,
+                max_n_retries: 1,
+            },
+        )
+    };
+    assert!(matches!(err, ErrorCode::Ok));
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err =
+        unsafe { access_protected_information(client, fixture::id(28), &mut response, &mut error) };
+    assert!(!matches!(err, ErrorCode::Ok));
+
+    // The message has the full error chain, down to the refused connection.
+    let message = last_error_message().unwrap();
+    assert!(message.contains(": "), "{message}");
+
+    unsafe { destroy_senhasegura_client(client) };
+}
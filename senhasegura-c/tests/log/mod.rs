@@ -0,0 +1,111 @@
+use std::{
+    ffi::{c_char, c_void, CStr},
+    sync::Mutex,
+};
+
+use senhasegura_c::{
+    access_protected_information, senhasegura_set_log_callback, senhasegura_set_log_level,
+    ApiError, ErrorCode, LogLevel,
+};
+
+use crate::fixture::{self, Fixture};
+
+/// Events received by the callback, i.e. their level, target and message.
+static EVENTS: Mutex<Vec<(LogLevel, String, String)>> = Mutex::new(Vec::new());
+
+extern "C" fn on_log(
+    level: u32,
+    target: *const c_char,
+    message: *const c_char,
+    _user_data: *mut c_void,
+) {
+    let target = unsafe { CStr::from_ptr(target) }.to_str().unwrap();
+    let message = unsafe { CStr::from_ptr(message) }.to_str().unwrap();
+
+    EVENTS.lock().unwrap().push((
+        LogLevel::try_from(level).unwrap(),
+        target.to_string(),
+        message.to_string(),
+    ));
+}
+
+/// Returns the messages of the events received so far which contain the marker, since other tests
+/// also log concurrently.
+fn messages(marker: &str) -> Vec<String> {
+    EVENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, _, message)| message.contains(marker))
+        .map(|(_, _, message)| message.clone())
+        .collect()
+}
+
+#[test]
+fn test_log_callback() {
+    let err =
+        senhasegura_set_log_callback(LogLevel::Warn as u32, Some(on_log), std::ptr::null_mut());
+    assert!(matches!(err, ErrorCode::Ok));
+
+    // Events above the level, or of other targets (i.e. the library's dependencies), are ignored.
+    tracing::info!(target: "senhasegura_c", "marker-1 ignored");
+    tracing::warn!(target: "hyper", "marker-1 ignored");
+
+    // Secrets are redacted.
+    tracing::warn!(
+        target: "senhasegura_c",
+        client_secret = "s3cr3t",
+        access_token = "t0k3n",
+        base_url = "http://localhost:5000",
+        "marker-1 forwarded"
+    );
+
+    assert_eq!(
+        messages("marker-1"),
+        [
+            "marker-1 forwarded client_secret=[REDACTED] access_token=[REDACTED] \
+             base_url=http://localhost:5000"
+        ]
+    );
+
+    // Unknown levels are rejected.
+    let err = senhasegura_set_log_level(6);
+    assert!(matches!(err, ErrorCode::InvalidArgument));
+
+    let err = senhasegura_set_log_callback(42, Some(on_log), std::ptr::null_mut());
+    assert!(matches!(err, ErrorCode::InvalidArgument));
+
+    // The level may be changed at runtime, e.g. to log failed calls.
+    let err = senhasegura_set_log_level(LogLevel::Debug as u32);
+    assert!(matches!(err, ErrorCode::Ok));
+
+    let fixture = Fixture::start();
+
+    let mut error = ApiError::default();
+    let err = unsafe {
+        access_protected_information(
+            fixture.client(),
+            fixture::id(28),
+            std::ptr::null_mut(),
+            &mut error,
+        )
+    };
+    assert!(matches!(err, ErrorCode::InvalidNullPointer));
+
+    assert!(EVENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(level, target, message)| {
+            *level == LogLevel::Debug
+                && target.starts_with("senhasegura_c")
+                && message.contains("invalid null pointer: `response`")
+        }));
+
+    // A null callback disables logging.
+    let err = senhasegura_set_log_callback(LogLevel::Trace as u32, None, std::ptr::null_mut());
+    assert!(matches!(err, ErrorCode::Ok));
+
+    tracing::error!(target: "senhasegura_c", "marker-2 ignored");
+    assert!(messages("marker-2").is_empty());
+}
@@ -0,0 +1,15 @@
+mod fixture;
+
+mod asynchronous;
+
+mod client;
+
+mod cpp;
+
+mod identifier;
+
+mod last_error;
+
+mod log;
+
+mod strings;
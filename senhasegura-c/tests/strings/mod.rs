@@ -0,0 +1,187 @@
+use std::ffi::CStr;
+
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_c::{
+    access_protected_information, free_access_protected_information_api_response, free_api_error,
+    AccessProtectedInformationApiResponse, ApiError, ErrorCode,
+};
+
+use crate::fixture::{self, Fixture};
+
+fn mount_info(fixture: &Fixture, id: i32, content: &str) {
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path(format!("/iso/pam/info/{id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "response": {
+                    "status": 200,
+                    "message": format!("Information {id}"),
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "id": id.to_string(),
+                    "tag": null,
+                    "type": "Access credential",
+                    "content": content
+                }
+            }))),
+    );
+}
+
+fn access(
+    fixture: &Fixture,
+    id: i32,
+    response: &mut AccessProtectedInformationApiResponse,
+    error: &mut ApiError,
+) -> ErrorCode {
+    unsafe { access_protected_information(fixture.client(), fixture::id(id), response, error) }
+}
+
+#[test]
+fn test_long_content() {
+    let fixture = Fixture::start();
+
+    // Larger than any buffer a caller would reasonably provide.
+    let content = "x".repeat(1 << 20);
+    mount_info(&fixture, 28, &content);
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = access(&fixture, 28, &mut response, &mut error);
+    assert!(matches!(err, ErrorCode::Ok));
+
+    let info = &response.info;
+    assert_eq!(info.id, 28);
+    assert!(info.tag.is_null());
+    assert_eq!(
+        unsafe { CStr::from_ptr(info.r#type) }.to_str().unwrap(),
+        "Access credential"
+    );
+    assert_eq!(
+        unsafe { CStr::from_ptr(info.content) }.to_str().unwrap(),
+        content
+    );
+
+    unsafe { free_access_protected_information_api_response(&mut response) };
+
+    assert!(response.response.message.is_null());
+    assert!(response.info.r#type.is_null());
+    assert!(response.info.content.is_null());
+
+    // Freeing twice (or a null pointer) is a no-op.
+    unsafe { free_access_protected_information_api_response(&mut response) };
+    unsafe { free_access_protected_information_api_response(std::ptr::null_mut()) };
+}
+
+#[test]
+fn test_embedded_nul_content() {
+    let fixture = Fixture::start();
+
+    mount_info(&fixture, 28, "pass\0word");
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = access(&fixture, 28, &mut response, &mut error);
+    assert!(matches!(err, ErrorCode::Ok));
+
+    // The content is read in full by its length, rather than up to the first NUL character.
+    let info = &response.info;
+    assert_eq!(info.content_len, 9);
+    assert_eq!(
+        unsafe { std::slice::from_raw_parts(info.content.cast::<u8>(), info.content_len) },
+        b"pass\0word"
+    );
+    assert_eq!(unsafe { CStr::from_ptr(info.content) }.to_bytes(), b"pass");
+
+    unsafe { free_access_protected_information_api_response(&mut response) };
+
+    assert!(response.info.content.is_null());
+    assert_eq!(response.info.content_len, 0);
+}
+
+#[test]
+fn test_embedded_nul_tag() {
+    let fixture = Fixture::start();
+
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path("/iso/pam/info/28"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "response": {
+                    "status": 200,
+                    "message": "Information 28",
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "id": "28",
+                    "tag": "db\0prod",
+                    "type": "Access credential",
+                    "content": "password"
+                }
+            }))),
+    );
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = access(&fixture, 28, &mut response, &mut error);
+    assert!(matches!(err, ErrorCode::InvalidString));
+
+    // Strings allocated before the failure are freed, and the response is left untouched.
+    assert!(response.response.message.is_null());
+    assert!(response.info.r#type.is_null());
+    assert!(response.info.content.is_null());
+}
+
+#[test]
+fn test_api_error() {
+    let fixture = Fixture::start();
+
+    fixture.mount(
+        Mock::given(method("GET"))
+            .and(path("/iso/pam/info/28"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "response": {
+                    "status": 400,
+                    "message": "1023: Information not found",
+                    "error": true,
+                    "error_code": 1023
+                },
+                "exception": {
+                    "code": 1023,
+                    "message": "1023: Information not found",
+                    "detail": null
+                }
+            }))),
+    );
+
+    let mut response = AccessProtectedInformationApiResponse::default();
+    let mut error = ApiError::default();
+
+    let err = access(&fixture, 28, &mut response, &mut error);
+    assert!(matches!(err, ErrorCode::Api));
+
+    assert!(response.info.content.is_null());
+    assert_eq!(error.exception.code, 1023);
+    assert_eq!(
+        unsafe { CStr::from_ptr(error.exception.message) }
+            .to_str()
+            .unwrap(),
+        "1023: Information not found"
+    );
+    assert!(error.exception.detail.is_null());
+
+    unsafe { free_api_error(&mut error) };
+
+    assert!(error.response.message.is_null());
+    assert!(error.exception.message.is_null());
+}
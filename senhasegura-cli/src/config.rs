@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use senhasegura_rs::{ClientConfig, SenhaseguraClient};
+
+use crate::ConnectionArgs;
+
+/// Returns the default path of the configuration file (i.e. `~/.config/senhasegura/config.toml`).
+fn default_config_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("senhasegura").join("config.toml"))
+}
+
+/// Loads the profile from the configuration file (see [ClientConfig::from_file]).
+///
+/// A missing file or profile is only an error if they were explicitly selected.
+fn load_profile(args: &ConnectionArgs) -> anyhow::Result<ClientConfig> {
+    let Some(path) = args.config.clone().or_else(default_config_path) else {
+        return Ok(ClientConfig::default());
+    };
+
+    if args.config.is_none() && args.profile.is_none() && !path.exists() {
+        return Ok(ClientConfig::default());
+    }
+
+    match &args.profile {
+        Some(profile) => Ok(ClientConfig::from_file(&path, profile)?),
+        None => Ok(ClientConfig::read_profiles(&path)?
+            .remove("default")
+            .unwrap_or_default()),
+    }
+}
+
+/// Builds the client, from flags, environment variables and the configuration file, in this order
+/// of precedence.
+pub(crate) fn build_client(args: &ConnectionArgs) -> anyhow::Result<SenhaseguraClient> {
+    let config = ClientConfig {
+        base_url: args.base_url.clone(),
+        client_id: args.client_id.clone(),
+        client_secret: args.client_secret.clone(),
+        client_secret_file: args.client_secret_file.clone(),
+        request_timeout_secs: args.request_timeout_secs,
+        base_retry_delay_secs: None,
+        max_n_retries: args.max_n_retries,
+    }
+    .or(load_profile(args)?);
+
+    let builder = config
+        .into_builder()
+        .context("invalid connection settings (see --help)")?;
+
+    Ok(builder.build()?)
+}
@@ -0,0 +1,52 @@
+use senhasegura_rs::{
+    Error, ExceptionCode, PAMCoreExceptionCode, ProtectedInformationExceptionCode,
+};
+
+/// Exit codes, so that the tool may be scripted.
+pub(crate) const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0   Success
+  1   Other error (e.g. invalid configuration)
+  2   Invalid usage
+  3   Transport error (e.g. the API is unreachable)
+  4   API error
+  10  Information not found (1023)
+  11  Inactive information (1024)
+  12  The information content was not informed (1026)";
+
+/// Other error (e.g. invalid configuration).
+pub(crate) const OTHER: u8 = 1;
+
+/// Transport error (e.g. the API is unreachable).
+pub(crate) const TRANSPORT: u8 = 3;
+
+/// API error, without a known exception code.
+pub(crate) const API: u8 = 4;
+
+/// Returns the exit code of the error.
+pub(crate) fn of(error: &anyhow::Error) -> u8 {
+    match error.downcast_ref::<Error>() {
+        Some(error) => of_error(error),
+        None => OTHER,
+    }
+}
+
+/// Returns the exit code of the API client error.
+pub(crate) fn of_error(error: &Error) -> u8 {
+    use ProtectedInformationExceptionCode::*;
+
+    match error {
+        Error::Api(api_error) => match api_error.exception.as_ref().map(|e| &e.code) {
+            Some(ExceptionCode::PAMCore(PAMCoreExceptionCode::ProtectedInformation(code))) => {
+                match code {
+                    InformationNotFound => 10,
+                    InactiveInformation => 11,
+                    MissingContentParameter => 12,
+                }
+            }
+            _ => API,
+        },
+        Error::Transport(_) => TRANSPORT,
+        Error::Other(_) => OTHER,
+    }
+}
@@ -0,0 +1,417 @@
+use std::{
+    ffi::OsString,
+    io::{Read, Write},
+    path::PathBuf,
+    process::ExitCode,
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::Context;
+use clap::{Args, Parser, Subcommand};
+use senhasegura_rs::{
+    AccessProtectedInformationApi, BatchAccessProtectedInformationApi,
+    CreateProtectedInformationApi, CreateProtectedInformationApiRequest,
+    DisableProtectedInformationApi, EnvMapping, ExecCommand, ProtectedInformationIdentifier,
+    SenhaseguraClient, Template, TemplateRenderer,
+};
+use tracing_subscriber::EnvFilter;
+use url::Url;
+
+mod config;
+
+mod exit_code;
+
+mod output;
+use output::Output;
+
+/// Senhasegura API command-line tool.
+///
+/// Connection settings are read from flags, environment variables and the profile of the
+/// configuration file (i.e. `~/.config/senhasegura/config.toml`), in this order of precedence.
+#[derive(Parser, Debug)]
+#[command(name = "senhasegura", version, about, after_help = exit_code::EXIT_CODES_HELP)]
+struct Cli {
+    #[command(flatten)]
+    connection: ConnectionArgs,
+
+    /// Output format.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t,
+        global = true,
+        env = "SENHASEGURA_OUTPUT"
+    )]
+    output: Output,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Connection settings.
+#[derive(Args, Debug)]
+pub(crate) struct ConnectionArgs {
+    /// Base URL of the Senhasegura API.
+    #[arg(long, global = true, env = "SENHASEGURA_BASE_URL")]
+    pub(crate) base_url: Option<Url>,
+
+    /// OAuth2 client credentials ID.
+    #[arg(long, global = true, env = "SENHASEGURA_CLIENT_ID")]
+    pub(crate) client_id: Option<String>,
+
+    /// OAuth2 client credentials secret.
+    #[arg(
+        long,
+        global = true,
+        env = "SENHASEGURA_CLIENT_SECRET",
+        hide_env_values = true
+    )]
+    pub(crate) client_secret: Option<String>,
+
+    /// File containing the OAuth2 client credentials secret.
+    #[arg(long, global = true, env = "SENHASEGURA_CLIENT_SECRET_FILE")]
+    pub(crate) client_secret_file: Option<PathBuf>,
+
+    /// Request timeout, in seconds.
+    #[arg(long, global = true, env = "SENHASEGURA_REQUEST_TIMEOUT_SECS")]
+    pub(crate) request_timeout_secs: Option<u64>,
+
+    /// Maximum number of retries.
+    #[arg(long, global = true, env = "SENHASEGURA_MAX_N_RETRIES")]
+    pub(crate) max_n_retries: Option<u32>,
+
+    /// Profile of the configuration file.
+    #[arg(long, global = true, env = "SENHASEGURA_PROFILE")]
+    pub(crate) profile: Option<String>,
+
+    /// Path of the configuration file.
+    #[arg(long, global = true, env = "SENHASEGURA_CONFIG")]
+    pub(crate) config: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Accesses a protected information item.
+    Get {
+        /// Protected information item's ID or tag.
+        #[arg(value_parser = parse_id)]
+        id: ProtectedInformationIdentifier,
+    },
+
+    /// Creates (or updates, by tag) a protected information item.
+    Create {
+        /// Information you wish to protect.
+        #[arg(
+            long,
+            required_unless_present = "content_file",
+            conflicts_with = "content_file"
+        )]
+        content: Option<String>,
+
+        /// File to read the information from ("-" for stdin).
+        #[arg(long)]
+        content_file: Option<PathBuf>,
+
+        /// Unique string to identify the protected item.
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Name assigned to the protected item.
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Information type.
+        #[arg(long = "type")]
+        r#type: Option<String>,
+    },
+
+    /// Disables a protected information item.
+    Disable {
+        /// Protected information item's ID or tag.
+        #[arg(value_parser = parse_id)]
+        id: ProtectedInformationIdentifier,
+    },
+
+    /// Accesses many protected information items at once.
+    ///
+    /// The API doesn't support enumerating items, thus they must be given explicitly. Exits with
+    /// the code of the first failed item, if any.
+    List {
+        /// Protected information items' IDs or tags.
+        #[arg(value_parser = parse_id, required_unless_present = "ids_file")]
+        ids: Vec<ProtectedInformationIdentifier>,
+
+        /// File to read IDs or tags from, one per line ("-" for stdin).
+        #[arg(long)]
+        ids_file: Option<PathBuf>,
+
+        /// Maximum number of items accessed concurrently.
+        #[arg(long, default_value_t = 8)]
+        max_concurrency: usize,
+    },
+
+    /// Executes a command with protected information injected into its environment.
+    ///
+    /// Signals are forwarded to the command, and its exit code is returned (or 128 + the signal
+    /// number, if it was killed by a signal). The client secret isn't passed on to the command.
+    Exec {
+        /// Environment variable set to the content of a protected information item.
+        #[arg(
+            short,
+            long = "env",
+            value_name = "NAME=info:<ID or TAG>",
+            required = true
+        )]
+        env: Vec<EnvMapping>,
+
+        /// Command to execute, and its arguments.
+        #[arg(last = true, required = true)]
+        command: Vec<OsString>,
+    },
+
+    /// Renders a template file with protected information.
+    ///
+    /// Placeholders such as `{{ info:db-prod }}` or `{{ info:28 type }}` are replaced by the
+    /// content (or the given field: id, tag, type or content) of the items. The name of the items
+    /// isn't available, since the API doesn't return it when accessing them. The destination is
+    /// written atomically, and only if its content changed.
+    Render {
+        /// Template file.
+        template: PathBuf,
+
+        /// Destination file.
+        destination: PathBuf,
+
+        /// Permissions of the destination file, in octal (Unix only).
+        #[arg(long, default_value = "600", value_parser = parse_mode)]
+        mode: u32,
+
+        /// Keeps re-rendering the destination whenever the items change.
+        #[arg(long)]
+        watch: bool,
+
+        /// Interval between polls of the items, in seconds, when watching.
+        #[arg(long, default_value_t = 60, requires = "watch")]
+        interval_secs: u64,
+
+        /// Shell command executed after the destination is re-rendered, when watching.
+        #[arg(long, requires = "watch")]
+        reload: Option<String>,
+    },
+}
+
+/// Parses a protected information identifier, which is an ID if numeric, or a tag otherwise.
+fn parse_id(s: &str) -> Result<ProtectedInformationIdentifier, String> {
+    if s.is_empty() {
+        return Err("identifier must not be empty".to_string());
+    }
+
+    Ok(match s.parse::<i32>() {
+        Ok(id) => id.into(),
+        Err(_) => s.into(),
+    })
+}
+
+/// Parses file permissions in octal.
+fn parse_mode(s: &str) -> Result<u32, String> {
+    u32::from_str_radix(s.trim_start_matches("0o"), 8).map_err(|e| e.to_string())
+}
+
+/// Reads a file, or stdin if the path is "-".
+fn read_input(path: &PathBuf) -> anyhow::Result<String> {
+    if path.as_os_str() == "-" {
+        let mut s = String::new();
+        std::io::stdin().read_to_string(&mut s)?;
+
+        return Ok(s);
+    }
+
+    std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "off".into()))
+        .with_writer(std::io::stderr)
+        .init();
+
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(code) => ExitCode::from(code),
+        Err(e) => {
+            eprintln!("error: {e:#}");
+
+            ExitCode::from(exit_code::of(&e))
+        }
+    }
+}
+
+async fn run(cli: Cli) -> anyhow::Result<u8> {
+    let client = config::build_client(&cli.connection)?;
+
+    let mut stdout = std::io::stdout().lock();
+
+    let code = match cli.command {
+        Command::Get { id } => {
+            let response = client.access_protected_information(id).await?;
+
+            cli.output.access(&mut stdout, &response)?;
+
+            0
+        }
+        Command::Create {
+            content,
+            content_file,
+            tag,
+            name,
+            r#type,
+        } => {
+            let content = match (content, content_file) {
+                (Some(content), _) => content,
+                (None, Some(path)) => read_input(&path)?,
+                (None, None) => unreachable!("content is required by clap"),
+            };
+
+            let response = client
+                .create_protected_information(CreateProtectedInformationApiRequest {
+                    name,
+                    content,
+                    identifier: tag,
+                    r#type,
+                })
+                .await?;
+
+            cli.output.create(&mut stdout, &response)?;
+
+            0
+        }
+        Command::Disable { id } => {
+            let response = client.disable_protected_information(id).await?;
+
+            cli.output.disable(&mut stdout, &response)?;
+
+            0
+        }
+        Command::List {
+            mut ids,
+            ids_file,
+            max_concurrency,
+        } => {
+            if let Some(path) = ids_file {
+                for line in read_input(&path)?.lines().map(str::trim) {
+                    if !line.is_empty() {
+                        ids.push(parse_id(line).map_err(anyhow::Error::msg)?);
+                    }
+                }
+            }
+
+            list(&client, ids, max_concurrency, cli.output, &mut stdout).await?
+        }
+        Command::Exec { env, command } => exec(&client, env, command).await?,
+        Command::Render {
+            template,
+            destination,
+            mode,
+            watch,
+            interval_secs,
+            reload,
+        } => {
+            let template = Template::read(&template)
+                .with_context(|| format!("failed to read template {}", template.display()))?;
+
+            let mut renderer = TemplateRenderer::new(template, destination).mode(mode);
+
+            if let Some(reload) = reload {
+                renderer = renderer.reload_command(shell_command(reload));
+            }
+
+            if watch {
+                renderer
+                    .watch(Arc::new(client), Duration::from_secs(interval_secs))
+                    .await?;
+            } else {
+                renderer.render(&client).await?;
+            }
+
+            0
+        }
+    };
+
+    stdout.flush()?;
+
+    Ok(code)
+}
+
+async fn list(
+    client: &SenhaseguraClient,
+    ids: Vec<ProtectedInformationIdentifier>,
+    max_concurrency: usize,
+    output: Output,
+    w: &mut impl Write,
+) -> anyhow::Result<u8> {
+    let mut results = client
+        .access_protected_information_batch(ids.clone(), max_concurrency)
+        .await;
+
+    // Keep the order of the input, skipping duplicates.
+    let mut seen = std::collections::HashSet::new();
+    let ids = ids
+        .into_iter()
+        .filter(|id| seen.insert(id.clone()))
+        .collect::<Vec<_>>();
+
+    let results = ids
+        .into_iter()
+        .filter_map(|id| results.remove(&id).map(|result| (id, result)))
+        .collect::<Vec<_>>();
+
+    output.list(w, &results)?;
+
+    let code = results
+        .iter()
+        .find_map(|(_, result)| result.as_ref().err())
+        .map(exit_code::of_error)
+        .unwrap_or(0);
+
+    Ok(code)
+}
+
+async fn exec(
+    client: &SenhaseguraClient,
+    env: Vec<EnvMapping>,
+    command: Vec<OsString>,
+) -> anyhow::Result<u8> {
+    let mut command = command.into_iter();
+    let program = command.next().context("missing command")?;
+
+    let status = ExecCommand::new(program)
+        .args(command)
+        .secret_envs(env)
+        .env_remove("SENHASEGURA_CLIENT_SECRET")
+        .run(client)
+        .await?;
+
+    if let Some(code) = status.code() {
+        return Ok(u8::try_from(code).unwrap_or(exit_code::OTHER));
+    }
+
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return Ok(128u8.saturating_add(signal as u8));
+    }
+
+    Ok(exit_code::OTHER)
+}
+
+/// Returns the program and arguments which execute the command through the shell.
+fn shell_command(command: String) -> [String; 3] {
+    if cfg!(windows) {
+        ["cmd".into(), "/C".into(), command]
+    } else {
+        ["sh".into(), "-c".into(), command]
+    }
+}
@@ -0,0 +1,184 @@
+use std::io::Write;
+
+use senhasegura_rs::{
+    AccessProtectedInformationApiResponse, CreateProtectedInformationApiResponse,
+    DisableProtectedInformationApiResponse, Error, ProtectedInformationIdentifier,
+};
+
+/// Output format.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Output {
+    /// Human-readable table, without protected content.
+    #[default]
+    Table,
+
+    /// API responses, as JSON.
+    Json,
+
+    /// Protected content only, as is.
+    Raw,
+}
+
+/// Result of accessing an item of `list`.
+#[derive(serde::Serialize, Debug)]
+struct ListItem<'a> {
+    id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response: Option<&'a AccessProtectedInformationApiResponse>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Output {
+    pub(crate) fn access(
+        self,
+        w: &mut impl Write,
+        response: &AccessProtectedInformationApiResponse,
+    ) -> anyhow::Result<()> {
+        let info = &response.info;
+
+        match self {
+            Self::Table => write_table(
+                w,
+                &["ID", "TAG", "TYPE"],
+                &[vec![info.id.to_string(), opt(&info.tag), opt(&info.r#type)]],
+            )?,
+            Self::Json => write_json(w, response)?,
+            Self::Raw => write!(w, "{}", info.content)?,
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn create(
+        self,
+        w: &mut impl Write,
+        response: &CreateProtectedInformationApiResponse,
+    ) -> anyhow::Result<()> {
+        let info = &response.info;
+
+        match self {
+            Self::Table => write_table(
+                w,
+                &["TAG", "NAME", "TYPE"],
+                &[vec![
+                    opt(&info.identifier),
+                    opt(&info.name),
+                    opt(&info.r#type),
+                ]],
+            )?,
+            Self::Json => write_json(w, response)?,
+            Self::Raw => {
+                if let Some(identifier) = &info.identifier {
+                    writeln!(w, "{identifier}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn disable(
+        self,
+        w: &mut impl Write,
+        response: &DisableProtectedInformationApiResponse,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Table => writeln!(w, "{}", response.response.message)?,
+            Self::Json => write_json(w, response)?,
+            Self::Raw => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn list(
+        self,
+        w: &mut impl Write,
+        results: &[(
+            ProtectedInformationIdentifier,
+            Result<AccessProtectedInformationApiResponse, Error>,
+        )],
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Table => {
+                let rows = results
+                    .iter()
+                    .map(|(id, result)| match result {
+                        Ok(response) => vec![
+                            response.info.id.to_string(),
+                            opt(&response.info.tag),
+                            opt(&response.info.r#type),
+                            "ok".to_string(),
+                        ],
+                        Err(e) => vec![id.to_string(), "-".into(), "-".into(), e.to_string()],
+                    })
+                    .collect::<Vec<_>>();
+
+                write_table(w, &["ID", "TAG", "TYPE", "STATUS"], &rows)?;
+            }
+            Self::Json => {
+                let items = results
+                    .iter()
+                    .map(|(id, result)| ListItem {
+                        id: id.to_string(),
+                        response: result.as_ref().ok(),
+                        error: result.as_ref().err().map(ToString::to_string),
+                    })
+                    .collect::<Vec<_>>();
+
+                write_json(w, &items)?;
+            }
+            Self::Raw => {
+                for (_, result) in results {
+                    if let Ok(response) = result {
+                        writeln!(w, "{}", response.info.content)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn opt(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+fn write_json(w: &mut impl Write, value: &impl serde::Serialize) -> anyhow::Result<()> {
+    serde_json::to_writer_pretty(&mut *w, value)?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+fn write_table(w: &mut impl Write, headers: &[&str], rows: &[Vec<String>]) -> anyhow::Result<()> {
+    let mut widths = headers.iter().map(|h| h.len()).collect::<Vec<_>>();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut write_row = |cells: &mut dyn Iterator<Item = &str>| -> std::io::Result<()> {
+        let line = cells
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        writeln!(w, "{}", line.trim_end())
+    };
+
+    write_row(&mut headers.iter().copied())?;
+
+    for row in rows {
+        write_row(&mut row.iter().map(String::as_str))?;
+    }
+
+    Ok(())
+}
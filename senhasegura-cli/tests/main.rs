@@ -0,0 +1,327 @@
+use std::process::Output;
+
+use senhasegura_mock::{MockServer, Seed, SeedItem};
+use tokio::process::Command;
+
+async fn server() -> MockServer {
+    let seed = Seed {
+        items: vec![
+            SeedItem {
+                id: Some(28),
+                content: "password".to_string(),
+                tag: Some("db-prod".to_string()),
+                r#type: Some("Access credential".to_string()),
+                ..Default::default()
+            },
+            SeedItem {
+                id: Some(29),
+                content: "api_key".to_string(),
+                active: false,
+                ..Default::default()
+            },
+        ],
+    };
+
+    MockServer::builder().seed(seed).start().await.unwrap()
+}
+
+fn senhasegura(server: &MockServer) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_senhasegura"));
+
+    command
+        .env_clear()
+        .env("SENHASEGURA_BASE_URL", server.url().as_str())
+        .env("SENHASEGURA_CLIENT_ID", server.client_id())
+        .env("SENHASEGURA_CLIENT_SECRET", server.client_secret())
+        .env("SENHASEGURA_MAX_N_RETRIES", "0");
+
+    command
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[tokio::test]
+async fn test_get() {
+    let server = server().await;
+
+    let output = senhasegura(&server)
+        .args(["get", "db-prod", "--output", "raw"])
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(stdout(&output), "password");
+
+    let output = senhasegura(&server)
+        .args(["get", "28"])
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(!stdout(&output).contains("password"));
+    assert!(stdout(&output).contains("Access credential"));
+
+    let output = senhasegura(&server)
+        .args(["-o", "json", "get", "28"])
+        .output()
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["info"]["content"], "password");
+}
+
+#[tokio::test]
+async fn test_get_exit_codes() {
+    let server = server().await;
+
+    let output = senhasegura(&server)
+        .args(["get", "30"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(10));
+
+    let output = senhasegura(&server)
+        .args(["get", "29"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(11));
+
+    let output = senhasegura(&server)
+        .args(["get", "28", "--client-secret", "wrong_secret"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = senhasegura(&server)
+        .args(["get", "28", "--base-url", "http://127.0.0.1:9"])
+        .output()
+        .await
+        .unwrap();
+    assert!(matches!(output.status.code(), Some(1 | 3)), "{output:?}");
+
+    let output = senhasegura(&server).args(["get"]).output().await.unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[tokio::test]
+async fn test_create_disable() {
+    let server = server().await;
+
+    let output = senhasegura(&server)
+        .args(["create", "--tag", "api", "--content", "secret"])
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let output = senhasegura(&server)
+        .args(["get", "api", "-o", "raw"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(stdout(&output), "secret");
+
+    let output = senhasegura(&server)
+        .args(["disable", "api"])
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let output = senhasegura(&server)
+        .args(["disable", "api"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(11));
+
+    let output = senhasegura(&server)
+        .args(["create", "--content", ""])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(12));
+}
+
+#[tokio::test]
+async fn test_list() {
+    let server = server().await;
+
+    let output = senhasegura(&server)
+        .args(["list", "db-prod", "28", "29", "-o", "json"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(11));
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = json.as_array().unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0]["id"], "db-prod");
+    assert_eq!(items[1]["response"]["info"]["content"], "password");
+    assert!(items[2]["error"].is_string());
+
+    let output = senhasegura(&server)
+        .args(["list", "db-prod", "28", "-o", "raw"])
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(stdout(&output), "password\npassword\n");
+}
+
+#[tokio::test]
+async fn test_profile() {
+    let server = server().await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+
+    std::fs::write(
+        &config,
+        format!(
+            r#"
+[default]
+base_url = "http://127.0.0.1:9"
+
+[mock]
+base_url = "{}"
+client_id = "{}"
+client_secret = "{}"
+"#,
+            server.url(),
+            server.client_id(),
+            server.client_secret()
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_senhasegura"))
+        .env_clear()
+        .args(["get", "28", "-o", "raw", "--profile", "mock", "--config"])
+        .arg(&config)
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(stdout(&output), "password");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_senhasegura"))
+        .env_clear()
+        .args(["get", "28", "--profile", "missing", "--config"])
+        .arg(&config)
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_exec() {
+    let server = server().await;
+
+    let output = senhasegura(&server)
+        .args(["exec", "--env", "DB_PASS=info:db-prod", "--"])
+        .args([
+            "sh",
+            "-c",
+            r#"printf %s "$DB_PASS${SENHASEGURA_CLIENT_SECRET}"; exit 7"#,
+        ])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(7), "{output:?}");
+    assert_eq!(stdout(&output), "password");
+
+    // The command isn't executed if any secret can't be resolved.
+    let output = senhasegura(&server)
+        .args([
+            "exec",
+            "-e",
+            "DB_PASS=info:db-prod",
+            "-e",
+            "API_KEY=info:29",
+        ])
+        .args(["--", "echo", "executed"])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(11), "{output:?}");
+    assert_eq!(stdout(&output), "");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_exec_signal() {
+    let server = server().await;
+
+    let child = senhasegura(&server)
+        .args(["exec", "--env", "DB_PASS=info:db-prod", "--"])
+        .args([
+            "sh",
+            "-c",
+            r#"trap 'exit 42' TERM; while true; do sleep 0.1; done"#,
+        ])
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // Give the command some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let status = Command::new("kill")
+        .args(["-TERM", &child.id().unwrap().to_string()])
+        .status()
+        .await
+        .unwrap();
+    assert!(status.success());
+
+    let output = child.wait_with_output().await.unwrap();
+    assert_eq!(output.status.code(), Some(42), "{output:?}");
+}
+
+#[tokio::test]
+async fn test_render() {
+    let server = server().await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let template = dir.path().join("app.conf.tmpl");
+    let destination = dir.path().join("app.conf");
+
+    std::fs::write(
+        &template,
+        "password = {{ info:db-prod }}\ntype = {{ info:28 type }}\n",
+    )
+    .unwrap();
+
+    let output = senhasegura(&server)
+        .arg("render")
+        .args([&template, &destination])
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        std::fs::read_to_string(&destination).unwrap(),
+        "password = password\ntype = Access credential\n"
+    );
+
+    std::fs::write(&template, "api_key = {{ info:29 }}\n").unwrap();
+
+    let output = senhasegura(&server)
+        .arg("render")
+        .args([&template, &destination])
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.status.code(), Some(11), "{output:?}");
+    assert!(std::fs::read_to_string(&destination)
+        .unwrap()
+        .starts_with("password"));
+}
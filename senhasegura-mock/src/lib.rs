@@ -0,0 +1,233 @@
+//! # senhasegura-mock
+//!
+//! Local mock server of the Senhasegura API.
+//!
+//! Serves the OAuth v2.0 token endpoint and the PAM Core routes from a stateful, in-memory store
+//! (i.e. [FakeSenhasegura]), so that protected information created through the server may be
+//! accessed and disabled afterwards. May be embedded in Rust tests or run as a standalone binary
+//! (i.e. `senhasegura-mock`) by tests written in other languages.
+//!
+//! # Example
+//!
+//! ```
+//! use senhasegura_mock::{MockServer, Seed, SeedItem};
+//! use senhasegura_rs::{AccessProtectedInformationApi, SenhaseguraClient};
+//!
+//! # tokio_test::block_on(async {
+//! let seed = Seed {
+//!     items: vec![SeedItem {
+//!         id: Some(28),
+//!         content: "password".to_string(),
+//!         ..Default::default()
+//!     }],
+//! };
+//!
+//! let server = MockServer::builder().seed(seed).start().await?;
+//!
+//! let client =
+//!     SenhaseguraClient::builder(server.url(), server.client_id(), server.client_secret())
+//!         .build()?;
+//!
+//! let response = client.access_protected_information(28).await?;
+//! assert_eq!(response.info.content, "password");
+//!
+//! # Ok::<_, anyhow::Error>(())
+//! # });
+//! ```
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use senhasegura_rs::testing::FakeSenhasegura;
+use tokio::{net::TcpListener, sync::oneshot, task::JoinHandle};
+use url::Url;
+
+mod routes;
+use routes::*;
+
+mod seed;
+pub use seed::*;
+
+/// Default client ID accepted by the mock server.
+pub const DEFAULT_CLIENT_ID: &str = "client_id";
+
+/// Default client secret accepted by the mock server.
+pub const DEFAULT_CLIENT_SECRET: &str = "client_secret";
+
+/// Running mock server.
+///
+/// The server is shut down when dropped (or by [shutdown](Self::shutdown)).
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<AppState>,
+
+    shutdown: Option<oneshot::Sender<()>>,
+    task: JoinHandle<std::io::Result<()>>,
+}
+
+impl MockServer {
+    /// Creates a new mock server builder.
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::new()
+    }
+
+    /// Starts a mock server on a random local port, with the default settings.
+    pub async fn start() -> anyhow::Result<Self> {
+        Self::builder().start().await
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the base URL of the server, to be used as the client's base URL.
+    pub fn url(&self) -> Url {
+        format!("http://{}", self.addr)
+            .parse()
+            .expect("socket address should be a valid URL")
+    }
+
+    /// Returns the client ID accepted by the server.
+    pub fn client_id(&self) -> &str {
+        &self.state.client_id
+    }
+
+    /// Returns the client secret accepted by the server.
+    pub fn client_secret(&self) -> &str {
+        &self.state.client_secret
+    }
+
+    /// Returns the fake backing the server, e.g. to inspect its state or inject faults.
+    pub fn fake(&self) -> &FakeSenhasegura {
+        &self.state.fake
+    }
+
+    /// Gracefully shuts down the server, waiting for in-flight requests to complete.
+    pub async fn shutdown(mut self) -> anyhow::Result<()> {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+
+        (&mut self.task).await??;
+
+        Ok(())
+    }
+
+    /// Waits until the server stops (e.g. due to an I/O error).
+    pub async fn wait(mut self) -> anyhow::Result<()> {
+        (&mut self.task).await??;
+
+        Ok(())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Mock server builder.
+#[derive(Debug)]
+pub struct MockServerBuilder {
+    addr: SocketAddr,
+
+    client_id: String,
+    client_secret: String,
+    token_ttl: Duration,
+
+    fake: FakeSenhasegura,
+    seed: Seed,
+}
+
+impl MockServerBuilder {
+    /// Creates a new mock server builder.
+    pub fn new() -> Self {
+        Self {
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+
+            client_id: DEFAULT_CLIENT_ID.to_string(),
+            client_secret: DEFAULT_CLIENT_SECRET.to_string(),
+            token_ttl: Duration::from_secs(3600),
+
+            fake: FakeSenhasegura::new(),
+            seed: Seed::default(),
+        }
+    }
+
+    /// Sets the address to listen on (defaults to a random port on localhost).
+    pub fn addr(mut self, addr: SocketAddr) -> Self {
+        self.addr = addr;
+        self
+    }
+
+    /// Sets the client credentials accepted by the server.
+    pub fn credentials(mut self, client_id: impl ToString, client_secret: impl ToString) -> Self {
+        self.client_id = client_id.to_string();
+        self.client_secret = client_secret.to_string();
+        self
+    }
+
+    /// Sets the lifetime of issued access tokens.
+    pub fn token_ttl(mut self, token_ttl: Duration) -> Self {
+        self.token_ttl = token_ttl;
+        self
+    }
+
+    /// Sets the fake backing the server, e.g. to share its state with the test.
+    pub fn fake(mut self, fake: FakeSenhasegura) -> Self {
+        self.fake = fake;
+        self
+    }
+
+    /// Sets the seed data, inserted into the fake when the server starts.
+    pub fn seed(mut self, seed: Seed) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Binds to the address and starts serving requests in the background.
+    ///
+    /// Must be called within a Tokio runtime.
+    pub async fn start(self) -> anyhow::Result<MockServer> {
+        self.seed.apply(&self.fake);
+
+        let state = Arc::new(AppState::new(
+            self.fake,
+            self.client_id,
+            self.client_secret,
+            self.token_ttl,
+        ));
+
+        let listener = TcpListener::bind(self.addr).await?;
+        let addr = listener.local_addr()?;
+
+        let (shutdown, shutdown_rx) = oneshot::channel();
+
+        let app = router(state.clone());
+        let task = tokio::spawn(async move {
+            axum::serve(listener, app)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+        });
+
+        tracing::info!(%addr, "mock server listening");
+
+        Ok(MockServer {
+            addr,
+            state,
+
+            shutdown: Some(shutdown),
+            task,
+        })
+    }
+}
+
+impl Default for MockServerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,66 @@
+use std::{io::Write, net::SocketAddr, path::PathBuf, time::Duration};
+
+use clap::Parser;
+use senhasegura_mock::{MockServer, Seed, DEFAULT_CLIENT_ID, DEFAULT_CLIENT_SECRET};
+use tracing_subscriber::EnvFilter;
+
+/// Local mock server of the Senhasegura API.
+///
+/// Prints the server's base URL to stdout once it's listening, so that tests may start it on a
+/// random port (i.e. `--addr 127.0.0.1:0`) and read the URL back.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Address to listen on.
+    #[arg(long, env = "SENHASEGURA_MOCK_ADDR", default_value = "127.0.0.1:0")]
+    addr: SocketAddr,
+
+    /// Seed data file (JSON or YAML).
+    #[arg(long, env = "SENHASEGURA_MOCK_SEED")]
+    seed: Option<PathBuf>,
+
+    /// Client ID accepted by the server.
+    #[arg(long, env = "SENHASEGURA_MOCK_CLIENT_ID", default_value = DEFAULT_CLIENT_ID)]
+    client_id: String,
+
+    /// Client secret accepted by the server.
+    #[arg(long, env = "SENHASEGURA_MOCK_CLIENT_SECRET", default_value = DEFAULT_CLIENT_SECRET)]
+    client_secret: String,
+
+    /// Lifetime of issued access tokens, in seconds.
+    #[arg(long, default_value_t = 3600)]
+    token_ttl_secs: u64,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = Args::parse();
+
+    let seed = match &args.seed {
+        Some(path) => Seed::from_file(path)?,
+        None => Seed::default(),
+    };
+
+    let server = MockServer::builder()
+        .addr(args.addr)
+        .credentials(args.client_id, args.client_secret)
+        .token_ttl(Duration::from_secs(args.token_ttl_secs))
+        .seed(seed)
+        .start()
+        .await?;
+
+    {
+        let mut stdout = std::io::stdout().lock();
+        writeln!(stdout, "{}", server.url())?;
+        stdout.flush()?;
+    }
+
+    tokio::signal::ctrl_c().await?;
+
+    server.shutdown().await
+}
@@ -0,0 +1,222 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{Path, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Form, Json, Router,
+};
+use base64::Engine;
+use senhasegura_rs::{
+    testing::FakeSenhasegura, AccessProtectedInformationApi, ApiError,
+    CreateProtectedInformationApi, CreateProtectedInformationApiRequest,
+    DisableProtectedInformationApi, Error,
+};
+
+/// Shared state of the mock server's routes.
+#[derive(Debug)]
+pub(crate) struct AppState {
+    pub(crate) fake: FakeSenhasegura,
+
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    pub(crate) token_ttl: Duration,
+
+    tokens: Mutex<Tokens>,
+}
+
+#[derive(Debug, Default)]
+struct Tokens {
+    n_issued: u64,
+    expires_at: HashMap<String, Instant>,
+}
+
+impl AppState {
+    pub(crate) fn new(
+        fake: FakeSenhasegura,
+        client_id: String,
+        client_secret: String,
+        token_ttl: Duration,
+    ) -> Self {
+        Self {
+            fake,
+            client_id,
+            client_secret,
+            token_ttl,
+            tokens: Mutex::default(),
+        }
+    }
+
+    fn issue_token(&self) -> String {
+        let mut tokens = self.tokens.lock().unwrap();
+
+        tokens.n_issued += 1;
+
+        let access_token = format!("access_token_{}", tokens.n_issued);
+        let expires_at = Instant::now() + self.token_ttl;
+
+        tokens.expires_at.insert(access_token.clone(), expires_at);
+
+        access_token
+    }
+
+    fn is_token_valid(&self, access_token: &str) -> bool {
+        let tokens = self.tokens.lock().unwrap();
+
+        tokens
+            .expires_at
+            .get(access_token)
+            .is_some_and(|expires_at| *expires_at > Instant::now())
+    }
+}
+
+pub(crate) fn router(state: Arc<AppState>) -> Router {
+    let pam = Router::new()
+        .route("/iso/pam/info", post(create_protected_information))
+        .route(
+            "/iso/pam/info/:id",
+            get(access_protected_information).delete(disable_protected_information),
+        )
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorize));
+
+    Router::new()
+        .route("/iso/oauth2/token", post(token))
+        .merge(pam)
+        .with_state(state)
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct TokenRequest {
+    grant_type: String,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+}
+
+/// Client credentials grant, with credentials either in the body or in basic authorization.
+///
+/// See [RFC 6749](https://datatracker.ietf.org/doc/html/rfc6749#section-4.4).
+async fn token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Form(request): Form<TokenRequest>,
+) -> Response {
+    if request.grant_type != "client_credentials" {
+        return oauth2_error(StatusCode::BAD_REQUEST, "unsupported_grant_type");
+    }
+
+    let credentials = basic_credentials(&headers).or(request.client_id.zip(request.client_secret));
+
+    match credentials {
+        Some((client_id, client_secret))
+            if client_id == state.client_id && client_secret == state.client_secret =>
+        {
+            Json(serde_json::json!({
+                "access_token": state.issue_token(),
+                "token_type": "bearer",
+                "expires_in": state.token_ttl.as_secs(),
+            }))
+            .into_response()
+        }
+        _ => oauth2_error(StatusCode::UNAUTHORIZED, "invalid_client"),
+    }
+}
+
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let encoded = value.strip_prefix("Basic ")?;
+
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()?;
+    let decoded = String::from_utf8(decoded).ok()?;
+
+    let (client_id, client_secret) = decoded.split_once(':')?;
+
+    Some((client_id.to_string(), client_secret.to_string()))
+}
+
+fn oauth2_error(status: StatusCode, error: &str) -> Response {
+    (status, Json(serde_json::json!({ "error": error }))).into_response()
+}
+
+/// Rejects requests without a valid bearer token, like the API does.
+async fn authorize(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    let access_token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match access_token {
+        Some(access_token) if state.is_token_valid(access_token) => next.run(request).await,
+        _ => unauthorized(),
+    }
+}
+
+fn unauthorized() -> Response {
+    let body = serde_json::json!({
+        "response": {
+            "status": 401,
+            "message": "Invalid token",
+            "error": true,
+            "error_code": 0,
+        },
+    });
+
+    (StatusCode::UNAUTHORIZED, Json(body)).into_response()
+}
+
+async fn access_protected_information(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Response {
+    respond(state.fake.access_protected_information(id).await)
+}
+
+async fn create_protected_information(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateProtectedInformationApiRequest>,
+) -> Response {
+    let result = state.fake.create_protected_information(request).await;
+
+    match result {
+        Ok(response) => (StatusCode::CREATED, Json(response)).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn disable_protected_information(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Response {
+    respond(state.fake.disable_protected_information(id).await)
+}
+
+fn respond<T>(result: Result<T, Error>) -> Response
+where
+    T: serde::Serialize,
+{
+    match result {
+        Ok(response) => Json(response).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+fn error_response(e: Error) -> Response {
+    match e {
+        Error::Api(api_error) => api_error_response(api_error),
+        e => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+fn api_error_response(api_error: ApiError) -> Response {
+    let status = StatusCode::from(api_error.response.status);
+
+    (status, Json(api_error)).into_response()
+}
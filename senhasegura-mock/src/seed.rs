@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use senhasegura_rs::testing::{FakeProtectedInformation, FakeSenhasegura};
+
+/// Seed data of the mock server.
+///
+/// May be loaded from JSON or YAML files, e.g.:
+///
+/// ```yaml
+/// items:
+///   - id: 28
+///     tag: db-prod
+///     type: Access credential
+///     content: password
+///   - name: API key
+///     content: api_key
+///     active: false
+/// ```
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Seed {
+    /// Protected information items.
+    #[serde(default)]
+    pub items: Vec<SeedItem>,
+}
+
+/// Protected information item of the seed data.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SeedItem {
+    /// Protected information item's ID, auto-incremented if omitted.
+    pub id: Option<i32>,
+
+    /// Name assigned to the protected item.
+    pub name: Option<String>,
+
+    /// Information you wish to protect.
+    pub content: String,
+
+    /// Unique string to identify the protected item.
+    pub tag: Option<String>,
+
+    /// Information type.
+    pub r#type: Option<String>,
+
+    /// Flag to indicate whether the item is active (i.e. not disabled).
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl Default for SeedItem {
+    fn default() -> Self {
+        Self {
+            id: None,
+            name: None,
+            content: String::new(),
+            tag: None,
+            r#type: None,
+            active: default_active(),
+        }
+    }
+}
+
+impl Seed {
+    /// Parses seed data from a JSON string.
+    pub fn from_json(s: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Parses seed data from a YAML string.
+    pub fn from_yaml(s: &str) -> anyhow::Result<Self> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Reads seed data from a file, parsed according to its extension (i.e. `.json`, `.yaml` or
+    /// `.yml`).
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+
+        let s = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read seed file {}", path.display()))?;
+
+        let seed = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&s),
+            Some("yaml" | "yml") => Self::from_yaml(&s),
+            _ => bail!("unsupported seed file extension: {}", path.display()),
+        };
+
+        seed.with_context(|| format!("failed to parse seed file {}", path.display()))
+    }
+
+    /// Inserts the seed data into the fake.
+    pub fn apply(&self, fake: &FakeSenhasegura) {
+        for item in &self.items {
+            let info = FakeProtectedInformation {
+                name: item.name.clone(),
+                content: item.content.clone(),
+                tag: item.tag.clone(),
+                r#type: item.r#type.clone(),
+                active: item.active,
+            };
+
+            match item.id {
+                Some(id) => fake.insert_with_id(id, info),
+                None => {
+                    fake.insert(info);
+                }
+            }
+        }
+    }
+}
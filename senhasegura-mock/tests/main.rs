@@ -0,0 +1,172 @@
+use std::{
+    io::{BufRead, BufReader},
+    process::{Command, Stdio},
+};
+
+use senhasegura_mock::{MockServer, Seed};
+use senhasegura_rs::{
+    AccessProtectedInformationApi, CreateProtectedInformationApi,
+    CreateProtectedInformationApiRequest, DisableProtectedInformationApi, Error, ExceptionCode,
+    PAMCoreExceptionCode, ProtectedInformationExceptionCode, SenhaseguraClient,
+};
+
+fn client(server: &MockServer) -> SenhaseguraClient {
+    SenhaseguraClient::builder(server.url(), server.client_id(), server.client_secret())
+        .disable_retries()
+        .build()
+        .unwrap()
+}
+
+fn exception_code(error: Error) -> Option<ProtectedInformationExceptionCode> {
+    match error {
+        Error::Api(api_error) => match api_error.exception?.code {
+            ExceptionCode::PAMCore(PAMCoreExceptionCode::ProtectedInformation(code)) => Some(code),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_mock_server() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let response = client
+        .create_protected_information(CreateProtectedInformationApiRequest {
+            name: Some("Database".to_string()),
+            content: "password".to_string(),
+            identifier: Some("db-prod".to_string()),
+            r#type: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.response.status, http::StatusCode::CREATED);
+
+    let response = client
+        .access_protected_information("db-prod")
+        .await
+        .unwrap();
+    assert_eq!(response.info.id, 1);
+    assert_eq!(response.info.content, "password");
+
+    let response = client.access_protected_information(1).await.unwrap();
+    assert_eq!(response.info.tag.as_deref(), Some("db-prod"));
+
+    client.disable_protected_information(1).await.unwrap();
+
+    let error = client.access_protected_information(1).await.unwrap_err();
+    assert_eq!(
+        exception_code(error),
+        Some(ProtectedInformationExceptionCode::InactiveInformation)
+    );
+
+    let error = client.access_protected_information(2).await.unwrap_err();
+    assert_eq!(
+        exception_code(error),
+        Some(ProtectedInformationExceptionCode::InformationNotFound)
+    );
+
+    assert_eq!(server.fake().n_requests(), 6);
+
+    server.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_server_seed() {
+    let seed = Seed::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/seed.yaml")).unwrap();
+
+    let server = MockServer::builder().seed(seed).start().await.unwrap();
+    let client = client(&server);
+
+    let response = client.access_protected_information(28).await.unwrap();
+    assert_eq!(response.info.r#type.as_deref(), Some("Access credential"));
+
+    // Created items are assigned IDs after the seeded ones.
+    client
+        .create_protected_information(CreateProtectedInformationApiRequest {
+            name: None,
+            content: "api_key".to_string(),
+            identifier: None,
+            r#type: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(server.fake().get(29).is_some());
+}
+
+#[tokio::test]
+async fn test_mock_server_json_seed() {
+    let seed = Seed::from_json(
+        r#"{ "items": [{ "tag": "inactive", "content": "password", "active": false }] }"#,
+    )
+    .unwrap();
+
+    let server = MockServer::builder().seed(seed).start().await.unwrap();
+
+    let error = client(&server)
+        .access_protected_information("inactive")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        exception_code(error),
+        Some(ProtectedInformationExceptionCode::InactiveInformation)
+    );
+}
+
+#[tokio::test]
+async fn test_mock_server_invalid_credentials() {
+    let server = MockServer::start().await.unwrap();
+
+    let client = SenhaseguraClient::builder(server.url(), "client_id", "wrong_secret")
+        .disable_retries()
+        .build()
+        .unwrap();
+
+    let error = client.access_protected_information(28).await.unwrap_err();
+    assert!(matches!(error, Error::Other(_)), "{error:?}");
+
+    assert_eq!(server.fake().n_requests(), 0);
+}
+
+#[tokio::test]
+async fn test_mock_server_faults() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    server.fake().fail_next(1);
+
+    let error = client.access_protected_information(28).await.unwrap_err();
+    assert!(error.is_unavailable(), "{error:?}");
+}
+
+#[test]
+fn test_mock_server_binary() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_senhasegura-mock"))
+        .args(["--addr", "127.0.0.1:0", "--seed"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/seed.yaml"))
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut url = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut url)
+        .unwrap();
+
+    let client =
+        SenhaseguraClient::builder(url.trim().parse().unwrap(), "client_id", "client_secret")
+            .disable_retries()
+            .build()
+            .unwrap();
+
+    let response = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(client.access_protected_information(28));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert_eq!(response.unwrap().info.id, 28);
+}
@@ -0,0 +1,234 @@
+"""Senhasegura API client for Python."""
+
+import builtins
+from typing import Dict, List, Optional, Union
+
+ProtectedInformationIdentifier = Union[int, str]
+"""Protected information item's ID or tag."""
+
+class Response:
+    """Response (i.e. "response") field."""
+
+    status: int
+    """HTTP status code."""
+
+    message: str
+    """Response message."""
+
+    error: bool
+    """Flag to indicate whether an error occurred."""
+
+    error_code: int
+    """Error code."""
+
+class Exception:
+    """Exception (i.e. "exception") field."""
+
+    code: int
+    """Exception code."""
+
+    message: str
+    """Exception message."""
+
+    detail: Optional[str]
+    """Exception detail."""
+
+class ProtectedInformationExceptionCode:
+    """Protected information exception codes, which compare equal to their integer values."""
+
+    InformationNotFound: ProtectedInformationExceptionCode
+    """Information not found (1023)."""
+
+    InactiveInformation: ProtectedInformationExceptionCode
+    """Inactive information (1024)."""
+
+    MissingContentParameter: ProtectedInformationExceptionCode
+    """The information content was not informed (1026)."""
+
+    def __int__(self) -> int: ...
+
+class AccessProtectedInformationResult:
+    """Access protected information result (i.e. "info") field."""
+
+    id: int
+    """Protected information item's unique identification code."""
+
+    tag: Optional[str]
+    """Information identifier."""
+
+    type: Optional[str]
+    """Information type."""
+
+    content: str
+    """Information you wish to protect."""
+
+class AccessProtectedInformationApiResponse:
+    """Access protected information API response."""
+
+    response: Response
+    """Response."""
+
+    info: AccessProtectedInformationResult
+    """Access protected information result."""
+
+class CreateProtectedInformationApiRequest:
+    """Create protected information API request."""
+
+    name: Optional[str]
+    """Name assigned to the protected item."""
+
+    content: str
+    """Information you wish to protect."""
+
+    identifier: Optional[str]
+    """Unique string to identify the protected item."""
+
+    type: Optional[str]
+    """Information type."""
+
+    def __init__(
+        self,
+        content: str,
+        *,
+        name: Optional[str] = None,
+        identifier: Optional[str] = None,
+        type: Optional[str] = None,
+    ) -> None:
+        """Creates a new create protected information API request."""
+
+class CreateProtectedInformationResult:
+    """Create protected information result (i.e. "info") field."""
+
+    name: Optional[str]
+    """Name assigned to the protected item."""
+
+    type: Optional[str]
+    """Information type."""
+
+    service: Optional[str]
+    """Name of the service associated to the information."""
+
+    url: Optional[str]
+    """URL associated to the information."""
+
+    content: str
+    """Information you wish to protect."""
+
+    users_allowed: Optional[str]
+    """Comma-separated ACL of users / groups."""
+
+    identifier: Optional[str]
+    """Unique string to identify the protected item."""
+
+class CreateProtectedInformationApiResponse:
+    """Create protected information API response."""
+
+    response: Response
+    """Response."""
+
+    info: CreateProtectedInformationResult
+    """Create protected information result."""
+
+class DisableProtectedInformationApiResponse:
+    """Disable protected information API response."""
+
+    response: Response
+    """Response."""
+
+class SenhaseguraError(builtins.Exception):
+    """Errors that can occur when interacting with Senhasegura's API."""
+
+class ApiError(SenhaseguraError):
+    """API error, with the `response` and `exception` fields of the API error response."""
+
+    response: Response
+    """Response."""
+
+    exception: Optional[Exception]
+    """Exception."""
+
+class InformationNotFoundError(ApiError):
+    """Information not found."""
+
+class InactiveInformationError(ApiError):
+    """Inactive information."""
+
+class MissingContentParameterError(ApiError):
+    """The information content was not informed."""
+
+class TransportError(SenhaseguraError):
+    """Transport error (e.g. network error)."""
+
+class OtherError(SenhaseguraError):
+    """Other error, which does not fit into the other categories."""
+
+BatchAccessProtectedInformationResult = Dict[
+    ProtectedInformationIdentifier,
+    Union[AccessProtectedInformationApiResponse, SenhaseguraError],
+]
+"""Batch access protected information result, by protected information item."""
+
+class SenhaseguraClient:
+    """Senhasegura API client.
+
+    Methods block until the request completes (releasing the GIL), while their `_async` variants
+    run them in the default executor of the running event loop.
+    """
+
+    def __init__(
+        self,
+        base_url: str,
+        client_id: str,
+        client_secret: str,
+        *,
+        request_timeout: Optional[int] = None,
+        base_retry_delay: Optional[int] = None,
+        max_n_retries: Optional[int] = None,
+    ) -> None:
+        """Creates a new Senhasegura API client.
+
+        The request timeout and the base delay of the exponential backoff retry policy are in
+        seconds.
+        """
+
+    def access_protected_information(
+        self, id: ProtectedInformationIdentifier
+    ) -> AccessProtectedInformationApiResponse:
+        """Returns the protected information item."""
+
+    async def access_protected_information_async(
+        self, id: ProtectedInformationIdentifier
+    ) -> AccessProtectedInformationApiResponse:
+        """Returns the protected information item, asynchronously."""
+
+    def access_protected_information_batch(
+        self, ids: List[ProtectedInformationIdentifier], max_concurrency: int = 8
+    ) -> BatchAccessProtectedInformationResult:
+        """Returns the protected information items, fetching at most `max_concurrency` of them
+        concurrently."""
+
+    async def access_protected_information_batch_async(
+        self, ids: List[ProtectedInformationIdentifier], max_concurrency: int = 8
+    ) -> BatchAccessProtectedInformationResult:
+        """Returns the protected information items, fetching at most `max_concurrency` of them
+        concurrently, asynchronously."""
+
+    def create_protected_information(
+        self, request: CreateProtectedInformationApiRequest
+    ) -> CreateProtectedInformationApiResponse:
+        """Creates a protected information item."""
+
+    async def create_protected_information_async(
+        self, request: CreateProtectedInformationApiRequest
+    ) -> CreateProtectedInformationApiResponse:
+        """Creates a protected information item, asynchronously."""
+
+    def disable_protected_information(
+        self, id: ProtectedInformationIdentifier
+    ) -> DisableProtectedInformationApiResponse:
+        """Disables the protected information item."""
+
+    async def disable_protected_information_async(
+        self, id: ProtectedInformationIdentifier
+    ) -> DisableProtectedInformationApiResponse:
+        """Disables the protected information item, asynchronously."""
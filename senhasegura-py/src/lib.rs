@@ -0,0 +1 @@
+pub use senhasegura_rs::*;
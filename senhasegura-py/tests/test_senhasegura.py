@@ -0,0 +1,203 @@
+"""Tests of the Python bindings against a local mock server of the API.
+
+Run `maturin develop` before running them, e.g. with `python -m unittest discover tests`.
+"""
+
+import asyncio
+import json
+import threading
+import unittest
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+import senhasegura
+
+
+def info(id):
+    return 200, {
+        "response": {
+            "status": 200,
+            "message": f"Information {id}",
+            "error": False,
+            "error_code": 0,
+        },
+        "info": {"id": "28", "tag": "db-prod", "type": "Access credential", "content": "password"},
+    }
+
+
+ROUTES = {
+    ("POST", "/iso/oauth2/token"): (
+        200,
+        {"access_token": "access_token", "token_type": "bearer", "expires_in": 3600},
+    ),
+    ("GET", "/iso/pam/info/28"): info(28),
+    ("GET", "/iso/pam/info/db-prod"): info(28),
+    ("GET", "/iso/pam/info/29"): (
+        404,
+        {
+            "response": {
+                "status": 404,
+                "message": "1023: Information not found",
+                "error": True,
+                "error_code": 1023,
+            },
+            "exception": {"code": 1023, "message": "1023: Information not found", "detail": None},
+        },
+    ),
+    ("POST", "/iso/pam/info"): (
+        201,
+        {
+            "response": {
+                "status": 201,
+                "message": "Information successfully registered!",
+                "error": False,
+                "error_code": 0,
+            },
+            "info": {
+                "name": "saas_vault1",
+                "type": "access credential",
+                "service": "saas_client",
+                "url": "10.10.10.2",
+                "content": "login: mt4adm, password: mt4admp4ss",
+                "users_allowed": "admin, account_manager, mscharra",
+                "identifier": "INFOSAASVAULT1",
+            },
+        },
+    ),
+    ("DELETE", "/iso/pam/info/28"): (
+        200,
+        {
+            "response": {
+                "status": 200,
+                "message": "Information successfully disabled",
+                "error": False,
+                "error_code": 0,
+            }
+        },
+    ),
+}
+
+
+class Handler(BaseHTTPRequestHandler):
+    def respond(self):
+        length = int(self.headers.get("Content-Length", 0))
+        self.rfile.read(length)
+
+        status, body = ROUTES.get((self.command, self.path), (404, {}))
+        body = json.dumps(body).encode()
+
+        self.send_response(status)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    do_GET = do_POST = do_DELETE = respond
+
+    def log_message(self, format, *args):
+        pass
+
+
+class SenhaseguraClientTest(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+        threading.Thread(target=cls.server.serve_forever, daemon=True).start()
+
+    @classmethod
+    def tearDownClass(cls):
+        cls.server.shutdown()
+        cls.server.server_close()
+
+    def setUp(self):
+        host, port = self.server.server_address
+
+        self.client = senhasegura.SenhaseguraClient(
+            f"http://{host}:{port}", "client_id", "client_secret", max_n_retries=1
+        )
+
+    def test_access_protected_information(self):
+        response = self.client.access_protected_information(28)
+
+        self.assertEqual(response.response.status, 200)
+        self.assertEqual(response.info.id, 28)
+        self.assertEqual(response.info.tag, "db-prod")
+        self.assertEqual(response.info.type, "Access credential")
+        self.assertEqual(response.info.content, "password")
+
+        response = self.client.access_protected_information("db-prod")
+
+        self.assertEqual(response.info.id, 28)
+
+    def test_access_protected_information_not_found(self):
+        with self.assertRaises(senhasegura.InformationNotFoundError) as cm:
+            self.client.access_protected_information(29)
+
+        error = cm.exception
+
+        self.assertIsInstance(error, senhasegura.ApiError)
+        self.assertIsInstance(error, senhasegura.SenhaseguraError)
+        self.assertEqual(str(error), "1023: Information not found")
+        self.assertEqual(error.response.status, 404)
+        self.assertEqual(
+            error.exception.code, senhasegura.ProtectedInformationExceptionCode.InformationNotFound
+        )
+        self.assertIsNone(error.exception.detail)
+
+    def test_access_protected_information_batch(self):
+        result = self.client.access_protected_information_batch([28, "db-prod", 29])
+
+        self.assertEqual(result[28].info.content, "password")
+        self.assertEqual(result["db-prod"].info.content, "password")
+        self.assertIsInstance(result[29], senhasegura.InformationNotFoundError)
+
+    def test_create_protected_information(self):
+        request = senhasegura.CreateProtectedInformationApiRequest(
+            "login: mt4adm, password: mt4admp4ss", name="saas_vault1", type="access credential"
+        )
+
+        response = self.client.create_protected_information(request)
+
+        self.assertEqual(response.response.status, 201)
+        self.assertEqual(response.info.identifier, "INFOSAASVAULT1")
+        self.assertEqual(response.info.service, "saas_client")
+
+    def test_disable_protected_information(self):
+        response = self.client.disable_protected_information(28)
+
+        self.assertEqual(response.response.message, "Information successfully disabled")
+
+    def test_invalid_identifier(self):
+        with self.assertRaises(TypeError):
+            self.client.access_protected_information(28.0)
+
+    def test_invalid_base_url(self):
+        with self.assertRaises(senhasegura.OtherError):
+            senhasegura.SenhaseguraClient("not a URL", "client_id", "client_secret")
+
+    def test_async(self):
+        async def main():
+            responses = await asyncio.gather(
+                self.client.access_protected_information_async(28),
+                self.client.access_protected_information_async("db-prod"),
+            )
+
+            self.assertEqual([r.info.id for r in responses], [28, 28])
+
+            with self.assertRaises(senhasegura.InformationNotFoundError):
+                await self.client.access_protected_information_async(29)
+
+            result = await self.client.access_protected_information_batch_async([28, 29])
+            self.assertIsInstance(result[29], senhasegura.ApiError)
+
+            request = senhasegura.CreateProtectedInformationApiRequest("content")
+            response = await self.client.create_protected_information_async(request)
+            self.assertEqual(response.info.name, "saas_vault1")
+
+            response = await self.client.disable_protected_information_async(28)
+            self.assertEqual(response.response.status, 200)
+
+        asyncio.run(main())
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -1,7 +1,9 @@
 use chrono::{DateTime, Duration, Utc};
-use oauth2::{AccessToken, EndpointNotSet, EndpointSet, TokenResponse};
+use oauth2::{
+    AccessToken, EndpointNotSet, EndpointSet, HttpClientError, RequestTokenError, TokenResponse,
+};
 
-use crate::SenhaseguraClient;
+use crate::{Error, HttpClient, Instance};
 
 pub(super) type OAuth2Client = oauth2::basic::BasicClient<
     EndpointNotSet,
@@ -26,30 +28,41 @@ impl AuthContext {
     }
 }
 
-impl SenhaseguraClient {
-    /// Authenticates the client.
+impl Instance {
+    /// Authenticates the client against the instance, reusing its access token until it expires
+    /// (unless forced to request a new one, e.g. to probe the instance).
     ///
     /// See [OAuth v2.0 authentication](https://docs.senhasegura.io/docs/a2a-how-to-authenticate-an-application#oauth-v20-authentication).
-    #[tracing::instrument(level = "trace", skip(self), err)]
-    pub(crate) async fn authenticate(&self) -> anyhow::Result<AccessToken> {
+    #[tracing::instrument(level = "trace", skip(self, http_client), fields(base_url = %self.base_url), err)]
+    pub(crate) async fn authenticate(
+        &self,
+        http_client: &HttpClient,
+        force: bool,
+    ) -> Result<AccessToken, Error> {
         let mut auth_ctx = self.auth_ctx.lock().await;
 
         match auth_ctx.as_ref() {
-            Some(ctx) if ctx.expires_at >= Utc::now() => return Ok(ctx.access_token.clone()),
+            Some(ctx) if !force && ctx.expires_at >= Utc::now() => {
+                return Ok(ctx.access_token.clone())
+            }
             _ => {}
         }
 
-        #[cfg(feature = "retry")]
-        let http_client = &custom::OAuth2HttpClient::new(&self.http_client);
-
-        #[cfg(not(feature = "retry"))]
-        let http_client = &self.http_client;
-
         let response = self
             .oauth2_client
             .exchange_client_credentials()
-            .request_async(http_client)
-            .await?;
+            .request_async(&custom::OAuth2HttpClient::new(http_client))
+            .await;
+
+        #[cfg(feature = "otel")]
+        crate::otel::record_token_refresh(&self.base_url, response.is_ok());
+
+        // Transport and server errors are reported as such, so that the instance is deemed
+        // unavailable, unlike rejected credentials (i.e. OAuth2 error responses).
+        let response = response.map_err(|e| match e {
+            RequestTokenError::Request(HttpClientError::Reqwest(e)) => *e,
+            e => Error::Other(e.into()),
+        })?;
 
         let access_token = response.access_token().to_owned();
         let expires_at = Utc::now()
@@ -64,40 +77,50 @@ impl SenhaseguraClient {
     }
 }
 
-#[cfg(feature = "retry")]
 mod custom {
     use std::{future::Future, pin::Pin};
 
     use oauth2::{AsyncHttpClient, HttpClientError, HttpRequest, HttpResponse};
 
+    use crate::{ApiError, Error, HttpClient};
+
+    /// OAuth2 HTTP client, which fails with [Error]s on transport and server errors, since the
+    /// OAuth2 client doesn't tell the latter apart from invalid responses.
     pub struct OAuth2HttpClient<'a> {
-        http_client: &'a reqwest_middleware::ClientWithMiddleware,
+        http_client: &'a HttpClient,
     }
 
     impl<'a> OAuth2HttpClient<'a> {
-        pub fn new(http_client: &'a reqwest_middleware::ClientWithMiddleware) -> Self {
+        pub fn new(http_client: &'a HttpClient) -> Self {
             Self { http_client }
         }
     }
 
     impl<'c> AsyncHttpClient<'c> for OAuth2HttpClient<'_> {
-        type Error = HttpClientError<reqwest_middleware::Error>;
+        type Error = HttpClientError<Error>;
 
         type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, Self::Error>> + Send + 'c>>;
 
         fn call(&'c self, request: HttpRequest) -> Self::Future {
             Box::pin(async move {
+                let request =
+                    reqwest::Request::try_from(request).map_err(|e| Box::new(e.into()))?;
+
                 let response = self
                     .http_client
-                    .execute(
-                        request
-                            .try_into()
-                            .map_err(|e| Box::new(reqwest_middleware::Error::Reqwest(e)))?,
-                    )
+                    .execute(request)
                     .await
-                    .map_err(Box::new)?;
+                    .map_err(|e| Box::new(e.into()))?;
+
+                let status = response.status();
+                if status.is_server_error() {
+                    let api_error =
+                        ApiError::from_status(status, "OAuth2 token endpoint is unavailable");
+
+                    return Err(Box::new(Error::Api(api_error)).into());
+                }
 
-                let mut builder = http::Response::builder().status(response.status());
+                let mut builder = http::Response::builder().status(status);
 
                 builder = builder.version(response.version());
 
@@ -110,7 +133,7 @@ mod custom {
                         response
                             .bytes()
                             .await
-                            .map_err(|e| Box::new(reqwest_middleware::Error::Reqwest(e)))?
+                            .map_err(|e| Box::new(e.into()))?
                             .to_vec(),
                     )
                     .map_err(HttpClientError::Http)
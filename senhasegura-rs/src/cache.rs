@@ -0,0 +1,431 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::{
+    AccessProtectedInformationApi, AccessProtectedInformationApiResponse, ApiError,
+    CreateProtectedInformationApi, CreateProtectedInformationApiRequest,
+    CreateProtectedInformationApiResponse, DisableProtectedInformationApi,
+    DisableProtectedInformationApiResponse, Error, ExceptionCode, PAMCoreExceptionCode,
+    ProtectedInformationApi, ProtectedInformationExceptionCode, ProtectedInformationIdentifier,
+};
+
+/// Protected information cache.
+///
+/// Wraps any [ProtectedInformationApi] implementation, keeping the accessed items in memory with
+/// their content encrypted by a random key, which is generated for every cache instance.
+///
+/// - Items are kept for the configured TTL, after which they're fetched again;
+/// - [InformationNotFound](ProtectedInformationExceptionCode::InformationNotFound) errors are
+///   cached for the configured negative TTL;
+/// - Expired items are still served for up to the configured maximum staleness when the inner
+///   client fails as the API is unavailable (see [Error::is_unavailable]), but not with other
+///   errors (e.g. decoding failures);
+/// - Items are invalidated when disabled or created through the cache.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use senhasegura_rs::{AccessProtectedInformationApi, CachedClient, SenhaseguraClient};
+///
+/// # tokio_test::block_on(async {
+/// let base_url = "https://senhasegura.acme.com".parse()?;
+///
+/// let client = SenhaseguraClient::builder(base_url, "client_id", "client_secret").build()?;
+///
+/// let client = CachedClient::builder(client)
+///     .ttl(Duration::from_secs(60))
+///     .max_staleness(Duration::from_secs(3600))
+///     .build();
+///
+/// println!("{:#?}", client.access_protected_information(28).await?);
+/// println!("{:#?}", client.metrics());
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+pub struct CachedClient<T> {
+    inner: T,
+
+    ttl: Duration,
+    negative_ttl: Duration,
+    max_staleness: Duration,
+
+    cipher: Aes256Gcm,
+    entries: Mutex<HashMap<ProtectedInformationIdentifier, CacheEntry>>,
+
+    metrics: AtomicCacheMetrics,
+}
+
+impl<T> CachedClient<T> {
+    /// Creates a new protected information cache builder.
+    pub fn builder(inner: T) -> CachedClientBuilder<T> {
+        CachedClientBuilder::new(inner)
+    }
+
+    /// Returns the inner client.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns a snapshot of the cache metrics.
+    pub fn metrics(&self) -> CacheMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Removes the protected information item from the cache.
+    ///
+    /// Entries referencing the same item by its ID or tag are removed as well.
+    pub fn invalidate(&self, id: &ProtectedInformationIdentifier) {
+        let mut entries = self.entries.lock().unwrap();
+
+        let len = entries.len();
+
+        entries.retain(|key, entry| key != id && !entry.refers_to(id));
+
+        let n_evicted = (len - entries.len()) as u64;
+        self.metrics
+            .invalidations
+            .fetch_add(n_evicted, Ordering::Relaxed);
+    }
+
+    /// Removes all protected information items from the cache.
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap();
+
+        let n_evicted = entries.len() as u64;
+        self.metrics
+            .invalidations
+            .fetch_add(n_evicted, Ordering::Relaxed);
+
+        entries.clear();
+    }
+
+    fn lookup(&self, id: &ProtectedInformationIdentifier) -> Lookup {
+        let entries = self.entries.lock().unwrap();
+
+        let Some(entry) = entries.get(id) else {
+            return Lookup::Miss;
+        };
+
+        let now = Instant::now();
+
+        match &entry.value {
+            CacheValue::Found { .. } if now < entry.expires_at => {
+                Lookup::Fresh(self.decrypt(&entry.value))
+            }
+            CacheValue::Found { .. } if now <= entry.expires_at + self.max_staleness => {
+                Lookup::Stale(self.decrypt(&entry.value))
+            }
+            CacheValue::NotFound(api_error) if now < entry.expires_at => {
+                Lookup::NotFound(api_error.clone())
+            }
+            _ => Lookup::Miss,
+        }
+    }
+
+    fn store(&self, id: ProtectedInformationIdentifier, value: CacheValue, ttl: Duration) {
+        let entry = CacheEntry {
+            value,
+            expires_at: Instant::now() + ttl,
+        };
+
+        self.entries.lock().unwrap().insert(id, entry);
+    }
+
+    fn encrypt(
+        &self,
+        mut response: AccessProtectedInformationApiResponse,
+    ) -> Result<CacheValue, Error> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+        let content = std::mem::take(&mut response.info.content);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, content.as_bytes())
+            .map_err(|e| anyhow!("failed to encrypt cache entry: {e}"))?;
+
+        Ok(CacheValue::Found {
+            response,
+            nonce: nonce.into(),
+            ciphertext,
+        })
+    }
+
+    fn decrypt(&self, value: &CacheValue) -> Result<AccessProtectedInformationApiResponse, Error> {
+        let CacheValue::Found {
+            response,
+            nonce,
+            ciphertext,
+        } = value
+        else {
+            unreachable!("only found entries hold encrypted content");
+        };
+
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext.as_slice())
+            .map_err(|e| anyhow!("failed to decrypt cache entry: {e}"))?;
+
+        let mut response = response.clone();
+        response.info.content = String::from_utf8(plaintext).map_err(anyhow::Error::from)?;
+
+        Ok(response)
+    }
+}
+
+/// Protected information cache builder.
+pub struct CachedClientBuilder<T> {
+    inner: T,
+
+    ttl: Option<Duration>,
+    negative_ttl: Option<Duration>,
+    max_staleness: Option<Duration>,
+}
+
+impl<T> CachedClientBuilder<T> {
+    /// Creates a new protected information cache builder.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+
+            ttl: None,
+            negative_ttl: None,
+            max_staleness: None,
+        }
+    }
+
+    /// Sets the time to live of cached items.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the time to live of cached "information not found" errors.
+    pub fn negative_ttl(mut self, negative_ttl: Duration) -> Self {
+        self.negative_ttl = Some(negative_ttl);
+        self
+    }
+
+    /// Sets for how long expired items may be served when the inner client fails.
+    pub fn max_staleness(mut self, max_staleness: Duration) -> Self {
+        self.max_staleness = Some(max_staleness);
+        self
+    }
+
+    /// Builds the protected information cache.
+    pub fn build(self) -> CachedClient<T> {
+        CachedClient {
+            inner: self.inner,
+
+            ttl: self.ttl.unwrap_or(Duration::from_secs(300)),
+            negative_ttl: self.negative_ttl.unwrap_or(Duration::from_secs(30)),
+            max_staleness: self.max_staleness.unwrap_or(Duration::ZERO),
+
+            cipher: Aes256Gcm::new(&Aes256Gcm::generate_key(OsRng)),
+            entries: Default::default(),
+
+            metrics: Default::default(),
+        }
+    }
+}
+
+/// Protected information cache metrics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheMetrics {
+    /// Number of requests served from fresh entries.
+    pub hits: u64,
+
+    /// Number of requests forwarded to the inner client.
+    pub misses: u64,
+
+    /// Number of requests served from cached "information not found" errors.
+    pub negative_hits: u64,
+
+    /// Number of requests served from expired entries, due to inner client failures.
+    pub stale_hits: u64,
+
+    /// Number of entries removed due to invalidation.
+    pub invalidations: u64,
+}
+
+#[derive(Default)]
+struct AtomicCacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    negative_hits: AtomicU64,
+    stale_hits: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl AtomicCacheMetrics {
+    fn snapshot(&self) -> CacheMetrics {
+        CacheMetrics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            negative_hits: self.negative_hits.load(Ordering::Relaxed),
+            stale_hits: self.stale_hits.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct CacheEntry {
+    value: CacheValue,
+    expires_at: Instant,
+}
+
+impl CacheEntry {
+    fn refers_to(&self, id: &ProtectedInformationIdentifier) -> bool {
+        match &self.value {
+            CacheValue::Found { response, .. } => {
+                *id == response.info.id || response.info.tag.as_ref().is_some_and(|tag| *id == *tag)
+            }
+            CacheValue::NotFound(_) => false,
+        }
+    }
+}
+
+enum CacheValue {
+    Found {
+        /// Response, without its content.
+        response: AccessProtectedInformationApiResponse,
+
+        nonce: [u8; 12],
+        ciphertext: Vec<u8>,
+    },
+    NotFound(ApiError),
+}
+
+enum Lookup {
+    Fresh(Result<AccessProtectedInformationApiResponse, Error>),
+    Stale(Result<AccessProtectedInformationApiResponse, Error>),
+    NotFound(ApiError),
+    Miss,
+}
+
+fn is_not_found(api_error: &ApiError) -> bool {
+    matches!(
+        api_error.exception.as_ref().map(|e| &e.code),
+        Some(ExceptionCode::PAMCore(
+            PAMCoreExceptionCode::ProtectedInformation(
+                ProtectedInformationExceptionCode::InformationNotFound
+            )
+        ))
+    )
+}
+
+#[async_trait]
+impl<T> AccessProtectedInformationApi for CachedClient<T>
+where
+    T: ProtectedInformationApi,
+{
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    async fn access_protected_information(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+    ) -> Result<AccessProtectedInformationApiResponse, Error> {
+        let id = id.into();
+
+        let stale = match self.lookup(&id) {
+            Lookup::Fresh(response) => {
+                self.metrics.hits.fetch_add(1, Ordering::Relaxed);
+
+                return response;
+            }
+            Lookup::NotFound(api_error) => {
+                self.metrics.negative_hits.fetch_add(1, Ordering::Relaxed);
+
+                return Err(Error::Api(api_error));
+            }
+            Lookup::Stale(response) => Some(response),
+            Lookup::Miss => None,
+        };
+
+        self.metrics.misses.fetch_add(1, Ordering::Relaxed);
+
+        match self.inner.access_protected_information(id.clone()).await {
+            Ok(response) => {
+                self.store(id, self.encrypt(response.clone())?, self.ttl);
+
+                Ok(response)
+            }
+            Err(Error::Api(api_error)) if is_not_found(&api_error) => {
+                self.store(
+                    id,
+                    CacheValue::NotFound(api_error.clone()),
+                    self.negative_ttl,
+                );
+
+                Err(Error::Api(api_error))
+            }
+            Err(e) => match stale {
+                Some(response) if e.is_unavailable() => {
+                    tracing::warn!(error = %e, "serving stale protected information");
+
+                    self.metrics.stale_hits.fetch_add(1, Ordering::Relaxed);
+
+                    response
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl<T> CreateProtectedInformationApi for CachedClient<T>
+where
+    T: ProtectedInformationApi,
+{
+    #[tracing::instrument(level = "trace", skip(self, request), err)]
+    async fn create_protected_information(
+        &self,
+        request: CreateProtectedInformationApiRequest,
+    ) -> Result<CreateProtectedInformationApiResponse, Error> {
+        let identifier = request.identifier.clone();
+
+        let response = self.inner.create_protected_information(request).await;
+
+        if let Some(identifier) = identifier {
+            self.invalidate(&identifier.into());
+        }
+
+        response
+    }
+}
+
+#[async_trait]
+impl<T> DisableProtectedInformationApi for CachedClient<T>
+where
+    T: ProtectedInformationApi,
+{
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    async fn disable_protected_information(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+    ) -> Result<DisableProtectedInformationApiResponse, Error> {
+        let id = id.into();
+
+        let response = self.inner.disable_protected_information(id.clone()).await;
+
+        self.invalidate(&id);
+
+        response
+    }
+}
@@ -0,0 +1,358 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use http::{header::CONTENT_TYPE, Extensions};
+use reqwest_middleware::{Middleware, Next};
+
+use crate::Error;
+
+/// Placeholder of scrubbed values.
+const REDACTED: &str = "[REDACTED]";
+
+/// Fields scrubbed from JSON and form bodies.
+const SCRUBBED_FIELDS: &[&str] = &["access_token", "refresh_token", "client_secret", "content"];
+
+/// Cassette mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Requests are sent to the API and recorded to the cassette file.
+    Record,
+
+    /// Requests are served from the cassette file, without reaching the API.
+    Replay,
+}
+
+/// Recorded HTTP interaction.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Interaction {
+    /// Recorded request.
+    pub request: RecordedRequest,
+
+    /// Recorded response.
+    pub response: RecordedResponse,
+}
+
+/// Recorded HTTP request.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// HTTP method.
+    pub method: String,
+
+    /// Path and query of the URL, without the base URL's origin.
+    pub path: String,
+
+    /// Scrubbed body.
+    pub body: Option<String>,
+}
+
+/// Recorded HTTP response.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RecordedResponse {
+    /// HTTP status code.
+    pub status: u16,
+
+    /// Content type.
+    pub content_type: Option<String>,
+
+    /// Scrubbed body.
+    pub body: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// Record-and-replay middleware of the client's HTTP stack.
+///
+/// In [Record](CassetteMode::Record) mode, every request / response pair is written to the
+/// cassette file, which is overwritten. In [Replay](CassetteMode::Replay) mode, requests are
+/// matched against the recorded interactions (by method, path and body), each of which is served
+/// at most once, in order; unmatched requests fail.
+///
+/// Bearer tokens, client secrets and `content` fields are scrubbed before being written, so
+/// cassettes may be committed. Request headers aren't recorded at all.
+///
+/// Clones share the same state.
+///
+/// # Example
+///
+/// ```no_run
+/// use senhasegura_rs::{AccessProtectedInformationApi, Cassette, SenhaseguraClient};
+///
+/// # tokio_test::block_on(async {
+/// let cassette = Cassette::replay("tests/cassettes/access.json")?;
+///
+/// let base_url = "https://senhasegura.acme.com".parse()?;
+///
+/// let client = SenhaseguraClient::builder(base_url, "client_id", "client_secret")
+///     .cassette(cassette.clone())
+///     .build()?;
+///
+/// client.access_protected_information(28).await?;
+///
+/// assert_eq!(cassette.remaining(), 0);
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    path: PathBuf,
+
+    state: Arc<Mutex<CassetteState>>,
+}
+
+#[derive(Debug)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+impl Cassette {
+    /// Creates a new cassette, loading the recorded interactions in replay mode.
+    pub fn new(path: impl AsRef<Path>, mode: CassetteMode) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+
+        let interactions = match mode {
+            CassetteMode::Record => Vec::new(),
+            CassetteMode::Replay => {
+                let bytes = std::fs::read(&path)?;
+
+                serde_json::from_slice::<CassetteFile>(&bytes)
+                    .map_err(|e| anyhow!("invalid cassette {}: {e}", path.display()))?
+                    .interactions
+            }
+        };
+
+        let used = vec![false; interactions.len()];
+
+        Ok(Self {
+            mode,
+            path,
+
+            state: Arc::new(Mutex::new(CassetteState { interactions, used })),
+        })
+    }
+
+    /// Creates a new cassette in record mode.
+    pub fn record(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::new(path, CassetteMode::Record)
+    }
+
+    /// Creates a new cassette in replay mode.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::new(path, CassetteMode::Replay)
+    }
+
+    /// Returns the cassette mode.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the recorded interactions.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state.lock().unwrap().interactions.clone()
+    }
+
+    /// Returns the number of recorded interactions which haven't been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .used
+            .iter()
+            .filter(|used| !**used)
+            .count()
+    }
+
+    fn save(&self, state: &CassetteState) -> Result<(), Error> {
+        let file = CassetteFile {
+            interactions: state.interactions.clone(),
+        };
+
+        let json = serde_json::to_vec_pretty(&file).map_err(anyhow::Error::from)?;
+
+        std::fs::write(&self.path, json)?;
+
+        Ok(())
+    }
+
+    async fn record_interaction(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let request = recorded_request(&req);
+
+        let response = next.run(req, extensions).await?;
+
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let content_type = content_type(&headers);
+
+        let interaction = Interaction {
+            request,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                content_type: content_type.clone(),
+                body: scrub(&String::from_utf8_lossy(&body), content_type.as_deref()),
+            },
+        };
+
+        {
+            let mut state = self.state.lock().unwrap();
+
+            state.interactions.push(interaction);
+            state.used.push(true);
+
+            self.save(&state).map_err(anyhow::Error::from)?;
+        }
+
+        let mut builder = http::Response::builder().status(status).version(version);
+
+        for (name, value) in headers.iter() {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.body(body).map_err(anyhow::Error::from)?;
+
+        Ok(response.into())
+    }
+
+    fn replay_interaction(
+        &self,
+        req: reqwest::Request,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let request = recorded_request(&req);
+
+        let mut state = self.state.lock().unwrap();
+        let CassetteState { interactions, used } = &mut *state;
+
+        let Some((interaction, used)) = interactions
+            .iter()
+            .zip(used.iter_mut())
+            .find(|(interaction, used)| !**used && interaction.request == request)
+        else {
+            return Err(reqwest_middleware::Error::Middleware(anyhow!(
+                "no matching interaction in cassette {} for {} {}",
+                self.path.display(),
+                request.method,
+                request.path
+            )));
+        };
+
+        *used = true;
+
+        let mut builder = http::Response::builder().status(interaction.response.status);
+
+        if let Some(content_type) = &interaction.response.content_type {
+            builder = builder.header(CONTENT_TYPE, content_type);
+        }
+
+        let response = builder
+            .body(interaction.response.body.clone())
+            .map_err(anyhow::Error::from)?;
+
+        Ok(response.into())
+    }
+}
+
+#[async_trait]
+impl Middleware for Cassette {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        match self.mode {
+            CassetteMode::Record => self.record_interaction(req, extensions, next).await,
+            CassetteMode::Replay => self.replay_interaction(req),
+        }
+    }
+}
+
+fn recorded_request(req: &reqwest::Request) -> RecordedRequest {
+    let url = req.url();
+
+    let path = match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    };
+
+    let body = req.body().and_then(|body| body.as_bytes()).map(|bytes| {
+        scrub(
+            &String::from_utf8_lossy(bytes),
+            content_type(req.headers()).as_deref(),
+        )
+    });
+
+    RecordedRequest {
+        method: req.method().to_string(),
+        path,
+        body,
+    }
+}
+
+fn content_type(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
+
+/// Scrubs sensitive fields from JSON and form bodies, according to their content type.
+fn scrub(body: &str, content_type: Option<&str>) -> String {
+    let content_type = content_type.unwrap_or_default();
+
+    if content_type.starts_with("application/x-www-form-urlencoded") {
+        return url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(
+                url::form_urlencoded::parse(body.as_bytes()).map(|(key, value)| {
+                    if SCRUBBED_FIELDS.contains(&key.as_ref()) {
+                        (key, REDACTED.into())
+                    } else {
+                        (key, value)
+                    }
+                }),
+            )
+            .finish();
+    }
+
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            scrub_json(&mut value);
+
+            value.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+fn scrub_json(value: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SCRUBBED_FIELDS.contains(&key.as_str()) && !value.is_null() {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    scrub_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(scrub_json),
+        _ => {}
+    }
+}
@@ -3,7 +3,7 @@ use crate::PAMCoreExceptionCode;
 /// HTTP status code.
 ///
 /// Wrapper around [http::StatusCode] to implement custom traits.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct StatusCode(http::StatusCode);
 
 impl std::ops::Deref for StatusCode {
@@ -32,6 +32,15 @@ impl PartialEq<http::StatusCode> for StatusCode {
     }
 }
 
+impl serde::Serialize for StatusCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u16(self.0.as_u16())
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for StatusCode {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -55,9 +64,13 @@ impl<'de> serde::Deserialize<'de> for StatusCode {
 // See: https://users.rust-lang.org/t/attribute-macro-confusion-in-pyo3/64832.
 
 /// Response (i.e. "response") field.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg(feature = "napi")]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 #[napi_derive::napi(object)]
 pub struct Response {
     /// HTTP status code.
@@ -75,9 +88,13 @@ pub struct Response {
 }
 
 /// Response (i.e. "response") field.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg(not(feature = "napi"))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct Response {
     /// HTTP status code.
     pub status: StatusCode,
@@ -92,8 +109,22 @@ pub struct Response {
     pub error_code: i32,
 }
 
+/// API operation (e.g. "access_protected_information"), as reported by tracing and telemetry.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(not(feature = "otel"), allow(dead_code))]
+pub(crate) struct Operation {
+    /// Operation name.
+    pub(crate) name: &'static str,
+
+    /// Kind of the protected information identifier (i.e. "id" or "tag"), if any.
+    pub(crate) identifier_kind: Option<&'static str>,
+
+    /// Template of the API path, relative to the base URL (i.e. without the identifier).
+    pub(crate) url_template: &'static str,
+}
+
 /// Senhasegura API exception codes.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum ExceptionCode {
     /// PAM Core exception code.
@@ -129,10 +160,13 @@ impl From<u16> for ExceptionCode {
 }
 
 /// Exception (i.e. "exception") field.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg(feature = "napi")]
 #[napi_derive::napi(object)]
-#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct Exception {
     /// Exception code.
     #[napi(ts_type = "number")]
@@ -146,9 +180,12 @@ pub struct Exception {
 }
 
 /// Exception (i.e. "exception") field.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg(not(feature = "napi"))]
-#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct Exception {
     /// Exception code.
     pub code: ExceptionCode,
@@ -219,6 +256,35 @@ mod senhasegura_js {
     impl ValidateNapiValue for ExceptionCode {}
 }
 
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use std::convert::Infallible;
+
+    use pyo3::{prelude::*, types::PyInt};
+
+    use super::*;
+
+    impl<'py> IntoPyObject<'py> for StatusCode {
+        type Target = PyInt;
+        type Output = Bound<'py, PyInt>;
+        type Error = Infallible;
+
+        fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+            self.0.as_u16().into_pyobject(py)
+        }
+    }
+
+    impl<'py> IntoPyObject<'py> for ExceptionCode {
+        type Target = PyInt;
+        type Output = Bound<'py, PyInt>;
+        type Error = Infallible;
+
+        fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+            u16::from(self).into_pyobject(py)
+        }
+    }
+}
+
 #[cfg(feature = "uniffi")]
 mod senhasegura_uniffi {
     use anyhow::anyhow;
@@ -0,0 +1,387 @@
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, bail};
+use url::Url;
+
+use crate::{Error, SenhaseguraClientBuilder};
+
+/// Environment variable of the base URL of the Senhasegura API.
+pub const ENV_BASE_URL: &str = "SENHASEGURA_BASE_URL";
+
+/// Environment variable of the OAuth2 client credentials ID.
+pub const ENV_CLIENT_ID: &str = "SENHASEGURA_CLIENT_ID";
+
+/// Environment variable of the OAuth2 client credentials secret.
+pub const ENV_CLIENT_SECRET: &str = "SENHASEGURA_CLIENT_SECRET";
+
+/// Environment variable of the path of a file containing the OAuth2 client credentials secret.
+pub const ENV_CLIENT_SECRET_FILE: &str = "SENHASEGURA_CLIENT_SECRET_FILE";
+
+/// Environment variable of the request timeout, in seconds.
+pub const ENV_REQUEST_TIMEOUT_SECS: &str = "SENHASEGURA_REQUEST_TIMEOUT_SECS";
+
+/// Environment variable of the base retry delay, in seconds.
+pub const ENV_BASE_RETRY_DELAY_SECS: &str = "SENHASEGURA_BASE_RETRY_DELAY_SECS";
+
+/// Environment variable of the maximum number of retries.
+pub const ENV_MAX_N_RETRIES: &str = "SENHASEGURA_MAX_N_RETRIES";
+
+/// Configuration of the Senhasegura API client.
+///
+/// Every setting is optional, so that configurations from many sources (e.g. flags, environment
+/// variables and files) may be merged with [ClientConfig::or] before being validated by
+/// [ClientConfig::into_builder].
+///
+/// The client secret may be given either as is, or as the path of a file containing it (e.g. a
+/// mounted Kubernetes secret), whose trailing newline is ignored.
+#[derive(serde::Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// Base URL of the Senhasegura API.
+    pub base_url: Option<Url>,
+
+    /// OAuth2 client credentials ID.
+    pub client_id: Option<String>,
+
+    /// OAuth2 client credentials secret.
+    pub client_secret: Option<String>,
+
+    /// Path of a file containing the OAuth2 client credentials secret.
+    pub client_secret_file: Option<PathBuf>,
+
+    /// Request timeout, in seconds.
+    pub request_timeout_secs: Option<u64>,
+
+    /// Base retry delay, in seconds.
+    pub base_retry_delay_secs: Option<u32>,
+
+    /// Maximum number of retries.
+    pub max_n_retries: Option<u32>,
+}
+
+impl std::fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("base_url", &self.base_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &self.client_secret.as_ref().map(|_| "***"))
+            .field("client_secret_file", &self.client_secret_file)
+            .field("request_timeout_secs", &self.request_timeout_secs)
+            .field("base_retry_delay_secs", &self.base_retry_delay_secs)
+            .field("max_n_retries", &self.max_n_retries)
+            .finish()
+    }
+}
+
+impl ClientConfig {
+    /// Reads the configuration from the environment.
+    ///
+    /// | Variable                            | Setting                 |
+    /// | ----------------------------------- | ----------------------- |
+    /// | `SENHASEGURA_BASE_URL`              | `base_url`              |
+    /// | `SENHASEGURA_CLIENT_ID`             | `client_id`             |
+    /// | `SENHASEGURA_CLIENT_SECRET`         | `client_secret`         |
+    /// | `SENHASEGURA_CLIENT_SECRET_FILE`    | `client_secret_file`    |
+    /// | `SENHASEGURA_REQUEST_TIMEOUT_SECS`  | `request_timeout_secs`  |
+    /// | `SENHASEGURA_BASE_RETRY_DELAY_SECS` | `base_retry_delay_secs` |
+    /// | `SENHASEGURA_MAX_N_RETRIES`         | `max_n_retries`         |
+    ///
+    /// Empty variables are ignored. Missing settings aren't an error until the configuration is
+    /// validated.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_lookup(|name| match std::env::var(name) {
+            Ok(value) => Ok(Some(value)),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(e) => Err(anyhow!("invalid {name}: {e}").into()),
+        })
+    }
+
+    /// Reads the configuration from the variables, as [ClientConfig::from_env] does from the
+    /// environment (e.g. to read them from elsewhere, or in tests).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use senhasegura_rs::ClientConfig;
+    ///
+    /// let config = ClientConfig::from_vars([("SENHASEGURA_CLIENT_ID", "client_id")])?;
+    /// assert_eq!(config.client_id.as_deref(), Some("client_id"));
+    ///
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn from_vars<K, V>(vars: impl IntoIterator<Item = (K, V)>) -> Result<Self, Error>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let vars: HashMap<String, String> = vars
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+
+        Self::from_lookup(|name| Ok(vars.get(name).cloned()))
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Result<Option<String>, Error>) -> Result<Self, Error> {
+        Ok(Self {
+            base_url: var(&lookup, ENV_BASE_URL)?,
+            client_id: var(&lookup, ENV_CLIENT_ID)?,
+            client_secret: var(&lookup, ENV_CLIENT_SECRET)?,
+            client_secret_file: var(&lookup, ENV_CLIENT_SECRET_FILE)?,
+            request_timeout_secs: var(&lookup, ENV_REQUEST_TIMEOUT_SECS)?,
+            base_retry_delay_secs: var(&lookup, ENV_BASE_RETRY_DELAY_SECS)?,
+            max_n_retries: var(&lookup, ENV_MAX_N_RETRIES)?,
+        })
+    }
+
+    /// Reads the configuration of a profile from a file.
+    ///
+    /// The file is parsed according to its extension (i.e. `.toml`, `.yaml` or `.yml`), and has a
+    /// table per profile, like AWS profiles:
+    ///
+    /// ```toml
+    /// [default]
+    /// base_url = "https://senhasegura.acme.com"
+    /// client_id = "client_id"
+    /// client_secret_file = "/run/secrets/senhasegura"
+    ///
+    /// [dr]
+    /// base_url = "https://senhasegura-dr.acme.com"
+    /// client_id = "client_id"
+    /// client_secret = "client_secret"
+    /// request_timeout_secs = 30
+    /// ```
+    ///
+    /// A relative `client_secret_file` is resolved against the directory of the file.
+    #[cfg(feature = "config")]
+    pub fn from_file(path: impl AsRef<std::path::Path>, profile: &str) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let mut profiles = Self::read_profiles(path)?;
+
+        match profiles.remove(profile) {
+            Some(config) => Ok(config),
+            None => {
+                let names = profiles.into_keys().collect::<Vec<_>>();
+
+                Err(anyhow!(
+                    "profile {profile:?} not found in config file {} (available: {})",
+                    path.display(),
+                    names.join(", ")
+                )
+                .into())
+            }
+        }
+    }
+
+    /// Reads the configuration of every profile from a file (see [ClientConfig::from_file]).
+    #[cfg(feature = "config")]
+    pub fn read_profiles(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<std::collections::BTreeMap<String, Self>, Error> {
+        let path = path.as_ref();
+
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("failed to read config file {}: {e}", path.display()))?;
+
+        let mut profiles: std::collections::BTreeMap<String, Self> =
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some("toml") => toml::from_str(&s).map_err(anyhow::Error::from),
+                Some("yaml" | "yml") => serde_yaml::from_str(&s).map_err(anyhow::Error::from),
+                _ => Err(anyhow!(
+                    "unsupported extension, expected .toml, .yaml or .yml"
+                )),
+            }
+            .map_err(|e| anyhow!("failed to parse config file {}: {e}", path.display()))?;
+
+        if let Some(dir) = path.parent() {
+            for config in profiles.values_mut() {
+                if let Some(file) = &config.client_secret_file {
+                    config.client_secret_file = Some(dir.join(file));
+                }
+            }
+        }
+
+        Ok(profiles)
+    }
+
+    /// Returns the configuration, with missing settings taken from the other one.
+    pub fn or(self, other: Self) -> Self {
+        // A secret given as is shouldn't be overridden by a file of lower precedence.
+        let (client_secret, client_secret_file) =
+            if self.client_secret.is_some() || self.client_secret_file.is_some() {
+                (self.client_secret, self.client_secret_file)
+            } else {
+                (other.client_secret, other.client_secret_file)
+            };
+
+        Self {
+            base_url: self.base_url.or(other.base_url),
+            client_id: self.client_id.or(other.client_id),
+            client_secret,
+            client_secret_file,
+            request_timeout_secs: self.request_timeout_secs.or(other.request_timeout_secs),
+            base_retry_delay_secs: self.base_retry_delay_secs.or(other.base_retry_delay_secs),
+            max_n_retries: self.max_n_retries.or(other.max_n_retries),
+        }
+    }
+
+    /// Validates the configuration, returning a client builder.
+    pub fn into_builder(self) -> Result<SenhaseguraClientBuilder, Error> {
+        Ok(self.validate()?)
+    }
+
+    fn validate(self) -> anyhow::Result<SenhaseguraClientBuilder> {
+        let Some(base_url) = self.base_url else {
+            bail!("missing base_url");
+        };
+        if base_url.cannot_be_a_base() || !matches!(base_url.scheme(), "http" | "https") {
+            bail!("invalid base_url {base_url}, expected an HTTP(S) URL");
+        }
+
+        let client_id = match self.client_id {
+            Some(client_id) if !client_id.is_empty() => client_id,
+            _ => bail!("missing client_id"),
+        };
+
+        let client_secret = match (self.client_secret, self.client_secret_file) {
+            (Some(_), Some(_)) => bail!("client_secret and client_secret_file are exclusive"),
+            (Some(client_secret), None) => client_secret,
+            (None, Some(path)) => read_secret_file(&path)?,
+            (None, None) => bail!("missing client_secret or client_secret_file"),
+        };
+        if client_secret.is_empty() {
+            bail!("client_secret must not be empty");
+        }
+
+        let mut builder = SenhaseguraClientBuilder::new(base_url, client_id, client_secret);
+
+        if let Some(secs) = self.request_timeout_secs {
+            if secs == 0 {
+                bail!("request_timeout_secs must be greater than 0");
+            }
+
+            builder = builder.request_timeout(Duration::from_secs(secs));
+        }
+
+        #[cfg(feature = "retry")]
+        {
+            if let Some(secs) = self.base_retry_delay_secs {
+                builder = builder.base_retry_delay_secs(secs);
+            }
+
+            if let Some(max_n_retries) = self.max_n_retries {
+                builder = builder.max_n_retries(max_n_retries);
+            }
+        }
+
+        #[cfg(not(feature = "retry"))]
+        if self.base_retry_delay_secs.is_some() || self.max_n_retries.is_some() {
+            bail!("base_retry_delay_secs and max_n_retries require the retry feature");
+        }
+
+        Ok(builder)
+    }
+}
+
+impl SenhaseguraClientBuilder {
+    /// Creates a new Senhasegura API client builder from the environment.
+    ///
+    /// See [ClientConfig::from_env] for the variables.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use senhasegura_rs::SenhaseguraClientBuilder;
+    ///
+    /// let client = SenhaseguraClientBuilder::from_env()?.build()?;
+    ///
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn from_env() -> Result<Self, Error> {
+        let builder = ClientConfig::from_env()?
+            .validate()
+            .map_err(|e| anyhow!("invalid configuration from environment: {e}"))?;
+
+        Ok(builder)
+    }
+
+    /// Creates a new Senhasegura API client builder from the variables, as
+    /// [SenhaseguraClientBuilder::from_env] does from the environment.
+    ///
+    /// See [ClientConfig::from_vars].
+    pub fn from_vars<K, V>(vars: impl IntoIterator<Item = (K, V)>) -> Result<Self, Error>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let builder = ClientConfig::from_vars(vars)?
+            .validate()
+            .map_err(|e| anyhow!("invalid configuration from variables: {e}"))?;
+
+        Ok(builder)
+    }
+
+    /// Creates a new Senhasegura API client builder from a profile of a configuration file.
+    ///
+    /// See [ClientConfig::from_file] for the format.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use senhasegura_rs::SenhaseguraClientBuilder;
+    ///
+    /// let client = SenhaseguraClientBuilder::from_config_file("senhasegura.toml", "default")?
+    ///     .max_n_retries(5)
+    ///     .build()?;
+    ///
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn from_config_file(
+        path: impl AsRef<std::path::Path>,
+        profile: &str,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let builder = ClientConfig::from_file(path, profile)?
+            .validate()
+            .map_err(|e| {
+                anyhow!(
+                    "invalid profile {profile:?} of config file {}: {e}",
+                    path.display()
+                )
+            })?;
+
+        Ok(builder)
+    }
+}
+
+/// Parses a variable, if set and not empty.
+fn var<T>(
+    lookup: impl Fn(&str) -> Result<Option<String>, Error>,
+    name: &str,
+) -> Result<Option<T>, Error>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let value = match lookup(name)? {
+        Some(value) if value.is_empty() => return Ok(None),
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    match value.parse() {
+        Ok(value) => Ok(Some(value)),
+        Err(e) => Err(anyhow!("invalid {name}: {e}").into()),
+    }
+}
+
+/// Reads the client secret from a file, without its trailing newline.
+fn read_secret_file(path: &std::path::Path) -> anyhow::Result<String> {
+    let s = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("failed to read client_secret_file {}: {e}", path.display()))?;
+
+    Ok(s.trim_end_matches(['\r', '\n']).to_string())
+}
@@ -0,0 +1,249 @@
+//! Object-safe variants of the API traits.
+//!
+//! The API traits (e.g. [AccessProtectedInformationApi]) accept any `impl Into<_>` identifier,
+//! which makes them ergonomic but not object-safe. The traits in this module take concrete types
+//! instead, so they may be used as trait objects (e.g. `Arc<dyn DynSenhaseguraApi>`):
+//!
+//! - Every implementation of the API traits also implements their object-safe variants;
+//! - Trait objects of the object-safe variants, as well as `&T`, `Box<T>` and `Arc<T>`, implement
+//!   the API traits, so both styles may be used interchangeably.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use senhasegura_rs::{AccessProtectedInformationApi, DynSenhaseguraApi, SenhaseguraClient};
+//!
+//! # tokio_test::block_on(async {
+//! let base_url = "https://senhasegura.acme.com".parse()?;
+//!
+//! let client: Arc<dyn DynSenhaseguraApi> =
+//!     Arc::new(SenhaseguraClient::builder(base_url, "client_id", "client_secret").build()?);
+//!
+//! println!("{:#?}", client.access_protected_information(28).await?);
+//!
+//! # Ok::<_, anyhow::Error>(())
+//! # });
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    AccessProtectedInformationApi, AccessProtectedInformationApiResponse,
+    CreateProtectedInformationApi, CreateProtectedInformationApiRequest,
+    CreateProtectedInformationApiResponse, DisableProtectedInformationApi,
+    DisableProtectedInformationApiResponse, Error, ProtectedInformationIdentifier,
+};
+
+/// Object-safe variant of [AccessProtectedInformationApi].
+#[async_trait]
+pub trait DynAccessProtectedInformationApi: Send + Sync {
+    /// Returns the protected information item.
+    async fn access_protected_information_dyn(
+        &self,
+        id: ProtectedInformationIdentifier,
+    ) -> Result<AccessProtectedInformationApiResponse, Error>;
+}
+
+#[async_trait]
+impl<T> DynAccessProtectedInformationApi for T
+where
+    T: AccessProtectedInformationApi,
+{
+    async fn access_protected_information_dyn(
+        &self,
+        id: ProtectedInformationIdentifier,
+    ) -> Result<AccessProtectedInformationApiResponse, Error> {
+        self.access_protected_information(id).await
+    }
+}
+
+/// Object-safe variant of [CreateProtectedInformationApi].
+#[async_trait]
+pub trait DynCreateProtectedInformationApi: Send + Sync {
+    /// Creates a protected information item.
+    async fn create_protected_information_dyn(
+        &self,
+        request: CreateProtectedInformationApiRequest,
+    ) -> Result<CreateProtectedInformationApiResponse, Error>;
+}
+
+#[async_trait]
+impl<T> DynCreateProtectedInformationApi for T
+where
+    T: CreateProtectedInformationApi,
+{
+    async fn create_protected_information_dyn(
+        &self,
+        request: CreateProtectedInformationApiRequest,
+    ) -> Result<CreateProtectedInformationApiResponse, Error> {
+        self.create_protected_information(request).await
+    }
+}
+
+/// Object-safe variant of [DisableProtectedInformationApi].
+#[async_trait]
+pub trait DynDisableProtectedInformationApi: Send + Sync {
+    /// Disables the protected information item.
+    async fn disable_protected_information_dyn(
+        &self,
+        id: ProtectedInformationIdentifier,
+    ) -> Result<DisableProtectedInformationApiResponse, Error>;
+}
+
+#[async_trait]
+impl<T> DynDisableProtectedInformationApi for T
+where
+    T: DisableProtectedInformationApi,
+{
+    async fn disable_protected_information_dyn(
+        &self,
+        id: ProtectedInformationIdentifier,
+    ) -> Result<DisableProtectedInformationApiResponse, Error> {
+        self.disable_protected_information(id).await
+    }
+}
+
+/// Object-safe variant of [ProtectedInformationApi](crate::ProtectedInformationApi).
+pub trait DynProtectedInformationApi:
+    DynAccessProtectedInformationApi
+    + DynCreateProtectedInformationApi
+    + DynDisableProtectedInformationApi
+{
+}
+
+impl<T> DynProtectedInformationApi for T where
+    T: DynAccessProtectedInformationApi
+        + DynCreateProtectedInformationApi
+        + DynDisableProtectedInformationApi
+{
+}
+
+/// Object-safe variant of [PAMCoreApi](crate::PAMCoreApi).
+pub trait DynPAMCoreApi: DynProtectedInformationApi {}
+
+impl<T> DynPAMCoreApi for T where T: DynProtectedInformationApi {}
+
+/// Object-safe variant of [SenhaseguraApi](crate::SenhaseguraApi).
+pub trait DynSenhaseguraApi: DynPAMCoreApi {}
+
+impl<T> DynSenhaseguraApi for T where T: DynPAMCoreApi {}
+
+macro_rules! impl_access_api {
+    ($($ty:ty),+) => {
+        $(
+            #[async_trait]
+            impl AccessProtectedInformationApi for $ty {
+                async fn access_protected_information(
+                    &self,
+                    id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+                ) -> Result<AccessProtectedInformationApiResponse, Error> {
+                    self.access_protected_information_dyn(id.into()).await
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_create_api {
+    ($($ty:ty),+) => {
+        $(
+            #[async_trait]
+            impl CreateProtectedInformationApi for $ty {
+                async fn create_protected_information(
+                    &self,
+                    request: CreateProtectedInformationApiRequest,
+                ) -> Result<CreateProtectedInformationApiResponse, Error> {
+                    self.create_protected_information_dyn(request).await
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_disable_api {
+    ($($ty:ty),+) => {
+        $(
+            #[async_trait]
+            impl DisableProtectedInformationApi for $ty {
+                async fn disable_protected_information(
+                    &self,
+                    id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+                ) -> Result<DisableProtectedInformationApiResponse, Error> {
+                    self.disable_protected_information_dyn(id.into()).await
+                }
+            }
+        )+
+    };
+}
+
+impl_access_api!(
+    dyn DynAccessProtectedInformationApi,
+    dyn DynProtectedInformationApi,
+    dyn DynPAMCoreApi,
+    dyn DynSenhaseguraApi
+);
+
+impl_create_api!(
+    dyn DynCreateProtectedInformationApi,
+    dyn DynProtectedInformationApi,
+    dyn DynPAMCoreApi,
+    dyn DynSenhaseguraApi
+);
+
+impl_disable_api!(
+    dyn DynDisableProtectedInformationApi,
+    dyn DynProtectedInformationApi,
+    dyn DynPAMCoreApi,
+    dyn DynSenhaseguraApi
+);
+
+macro_rules! impl_api_for_pointer {
+    ($($ty:ty),+) => {
+        $(
+            #[async_trait]
+            impl<T> AccessProtectedInformationApi for $ty
+            where
+                T: AccessProtectedInformationApi + ?Sized,
+            {
+                async fn access_protected_information(
+                    &self,
+                    id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+                ) -> Result<AccessProtectedInformationApiResponse, Error> {
+                    (**self).access_protected_information(id).await
+                }
+            }
+
+            #[async_trait]
+            impl<T> CreateProtectedInformationApi for $ty
+            where
+                T: CreateProtectedInformationApi + ?Sized,
+            {
+                async fn create_protected_information(
+                    &self,
+                    request: CreateProtectedInformationApiRequest,
+                ) -> Result<CreateProtectedInformationApiResponse, Error> {
+                    (**self).create_protected_information(request).await
+                }
+            }
+
+            #[async_trait]
+            impl<T> DisableProtectedInformationApi for $ty
+            where
+                T: DisableProtectedInformationApi + ?Sized,
+            {
+                async fn disable_protected_information(
+                    &self,
+                    id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+                ) -> Result<DisableProtectedInformationApiResponse, Error> {
+                    (**self).disable_protected_information(id).await
+                }
+            }
+        )+
+    };
+}
+
+impl_api_for_pointer!(&T, Box<T>, Arc<T>);
@@ -4,8 +4,6 @@ use crate::{Exception, Response};
 
 /// Errors that can occur when interacting with Senhasegura's API.
 #[derive(thiserror::Error, Debug)]
-#[cfg_attr(feature = "uniffi", derive(uniffi::Error))]
-#[cfg_attr(feature = "uniffi", uniffi(flat_error))]
 pub enum Error {
     /// API error.
     ///
@@ -28,9 +26,8 @@ pub enum Error {
 }
 
 /// API error response.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
-#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct ApiError {
     /// Response.
     pub response: Response,
@@ -39,6 +36,36 @@ pub struct ApiError {
     pub exception: Option<Exception>,
 }
 
+impl ApiError {
+    /// Returns an API error of the status code, for error responses without an API error body
+    /// (e.g. from a gateway, or the OAuth2 token endpoint).
+    pub(crate) fn from_status(status: http::StatusCode, message: impl ToString) -> Self {
+        Self {
+            response: Response {
+                status: status.into(),
+                message: message.to_string(),
+                error: true,
+                error_code: 0,
+            },
+            exception: None,
+        }
+    }
+}
+
+impl Error {
+    /// Returns whether the error indicates that the API is unavailable, rather than the request
+    /// being invalid (i.e. transport or server errors, including those of authentication).
+    ///
+    /// Other errors (e.g. decoding or URL errors) aren't, since they'd fail again elsewhere.
+    pub fn is_unavailable(&self) -> bool {
+        match self {
+            Self::Api(api_error) => api_error.response.status.is_server_error(),
+            Self::Transport(_) => true,
+            Self::Other(_) => false,
+        }
+    }
+}
+
 impl std::fmt::Display for ApiError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.response.message)
@@ -49,7 +76,9 @@ impl std::error::Error for ApiError {}
 
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
-        if err.is_connect() {
+        // Errors of the request itself (e.g. connection, timeout or body errors), as opposed to
+        // those of its response (e.g. decoding errors).
+        if err.is_connect() || err.is_timeout() || err.is_request() || err.is_body() {
             Self::Transport(err)
         } else {
             Self::Other(err.into())
@@ -171,3 +200,246 @@ mod senhasegura_js {
         }
     }
 }
+
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::{create_exception, exceptions::PyException, prelude::*};
+
+    use crate::{ExceptionCode, PAMCoreExceptionCode, ProtectedInformationExceptionCode};
+
+    create_exception!(
+        senhasegura,
+        SenhaseguraError,
+        PyException,
+        "Errors that can occur when interacting with Senhasegura's API."
+    );
+
+    create_exception!(
+        senhasegura,
+        ApiError,
+        SenhaseguraError,
+        "API error, with the `response` and `exception` fields of the API error response."
+    );
+
+    create_exception!(
+        senhasegura,
+        TransportError,
+        SenhaseguraError,
+        "Transport error (e.g. network error)."
+    );
+
+    create_exception!(
+        senhasegura,
+        OtherError,
+        SenhaseguraError,
+        "Other error, which does not fit into the other categories."
+    );
+
+    create_exception!(
+        senhasegura,
+        InformationNotFoundError,
+        ApiError,
+        "Information not found."
+    );
+
+    create_exception!(
+        senhasegura,
+        InactiveInformationError,
+        ApiError,
+        "Inactive information."
+    );
+
+    create_exception!(
+        senhasegura,
+        MissingContentParameterError,
+        ApiError,
+        "The information content was not informed."
+    );
+
+    /// Adds the exception classes to the module.
+    pub(crate) fn add_exceptions(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        let py = m.py();
+
+        m.add("SenhaseguraError", py.get_type::<SenhaseguraError>())?;
+        m.add("ApiError", py.get_type::<ApiError>())?;
+        m.add("TransportError", py.get_type::<TransportError>())?;
+        m.add("OtherError", py.get_type::<OtherError>())?;
+        m.add(
+            "InformationNotFoundError",
+            py.get_type::<InformationNotFoundError>(),
+        )?;
+        m.add(
+            "InactiveInformationError",
+            py.get_type::<InactiveInformationError>(),
+        )?;
+        m.add(
+            "MissingContentParameterError",
+            py.get_type::<MissingContentParameterError>(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Converts the API error into an exception of the class of its exception code (if known).
+    fn api_error_into_py(py: Python<'_>, api_error: super::ApiError) -> PyResult<PyErr> {
+        use ProtectedInformationExceptionCode::*;
+
+        let message = api_error.to_string();
+
+        let code = api_error.exception.as_ref().map(|e| &e.code);
+
+        let err = match code {
+            Some(ExceptionCode::PAMCore(PAMCoreExceptionCode::ProtectedInformation(code))) => {
+                match code {
+                    InformationNotFound => InformationNotFoundError::new_err(message),
+                    InactiveInformation => InactiveInformationError::new_err(message),
+                    MissingContentParameter => MissingContentParameterError::new_err(message),
+                }
+            }
+            _ => ApiError::new_err(message),
+        };
+
+        let value = err.value(py);
+        value.setattr("response", api_error.response)?;
+        value.setattr("exception", api_error.exception)?;
+
+        Ok(err)
+    }
+
+    impl From<super::Error> for PyErr {
+        fn from(value: super::Error) -> Self {
+            use super::Error::*;
+
+            match value {
+                Api(api_error) => {
+                    Python::with_gil(|py| api_error_into_py(py, api_error).unwrap_or_else(|e| e))
+                }
+                Transport(e) => TransportError::new_err(e.to_string()),
+                Other(e) => OtherError::new_err(e.to_string()),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "pyo3")]
+pub(crate) use senhasegura_py::add_exceptions;
+
+#[cfg(feature = "uniffi")]
+mod senhasegura_uniffi {
+    use crate::{
+        ExceptionCode, PAMCoreExceptionCode, ProtectedInformationExceptionCode, StatusCode,
+    };
+
+    /// Errors that can occur when interacting with Senhasegura's API, as seen by UniFFI bindings.
+    ///
+    /// API errors of known exception codes have their own variants, so that callers may branch on
+    /// them. Messages are named `reason`, since Kotlin exceptions already have a `message`.
+    #[derive(thiserror::Error, uniffi::Error, Debug)]
+    pub enum SenhaseguraError {
+        /// API error, of an exception code without its own variant.
+        #[error("{reason}")]
+        Api {
+            status: StatusCode,
+            reason: String,
+            exception_code: Option<ExceptionCode>,
+            detail: Option<String>,
+        },
+
+        /// Information not found.
+        #[error("{reason}")]
+        InformationNotFound {
+            status: StatusCode,
+            reason: String,
+            exception_code: Option<ExceptionCode>,
+            detail: Option<String>,
+        },
+
+        /// Inactive information.
+        #[error("{reason}")]
+        InactiveInformation {
+            status: StatusCode,
+            reason: String,
+            exception_code: Option<ExceptionCode>,
+            detail: Option<String>,
+        },
+
+        /// The information content was not informed.
+        #[error("{reason}")]
+        MissingContentParameter {
+            status: StatusCode,
+            reason: String,
+            exception_code: Option<ExceptionCode>,
+            detail: Option<String>,
+        },
+
+        /// Transport error (e.g. network error).
+        #[error("{reason}")]
+        Transport { reason: String },
+
+        /// Other error, which does not fit into the other categories.
+        #[error("{reason}")]
+        Other { reason: String },
+    }
+
+    impl From<super::Error> for SenhaseguraError {
+        fn from(value: super::Error) -> Self {
+            use super::Error::*;
+
+            match value {
+                Api(api_error) => {
+                    use ProtectedInformationExceptionCode::*;
+
+                    let reason = api_error.to_string();
+                    let status = api_error.response.status;
+                    let (exception_code, detail) = match api_error.exception {
+                        Some(exception) => (Some(exception.code), exception.detail),
+                        None => (None, None),
+                    };
+
+                    let code = match exception_code {
+                        Some(ExceptionCode::PAMCore(
+                            PAMCoreExceptionCode::ProtectedInformation(code),
+                        )) => Some(code),
+                        _ => None,
+                    };
+
+                    match code {
+                        Some(InformationNotFound) => Self::InformationNotFound {
+                            status,
+                            reason,
+                            exception_code,
+                            detail,
+                        },
+                        Some(InactiveInformation) => Self::InactiveInformation {
+                            status,
+                            reason,
+                            exception_code,
+                            detail,
+                        },
+                        Some(MissingContentParameter) => Self::MissingContentParameter {
+                            status,
+                            reason,
+                            exception_code,
+                            detail,
+                        },
+                        None => Self::Api {
+                            status,
+                            reason,
+                            exception_code,
+                            detail,
+                        },
+                    }
+                }
+                Transport(e) => Self::Transport {
+                    reason: e.to_string(),
+                },
+                Other(e) => Self::Other {
+                    reason: e.to_string(),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(feature = "uniffi")]
+pub use senhasegura_uniffi::SenhaseguraError;
@@ -0,0 +1,238 @@
+use std::{ffi::OsString, process::ExitStatus, str::FromStr};
+
+use anyhow::anyhow;
+
+use crate::{
+    AccessProtectedInformationApi, BatchAccessProtectedInformationApi, Error,
+    ProtectedInformationIdentifier,
+};
+
+/// Maximum number of protected information items accessed concurrently.
+const MAX_CONCURRENCY: usize = 8;
+
+/// Environment variable mapped to a protected information item.
+///
+/// Parsed from `NAME=info:<id or tag>` (e.g. `DB_PASS=info:db-prod`), where numeric identifiers are
+/// IDs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvMapping {
+    /// Environment variable name.
+    pub name: String,
+
+    /// Protected information item, whose content is the value of the variable.
+    pub id: ProtectedInformationIdentifier,
+}
+
+impl FromStr for EnvMapping {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, source)) = s.split_once('=') else {
+            return Err(anyhow!("invalid mapping {s:?}, expected NAME=info:<id or tag>").into());
+        };
+
+        if name.is_empty() || name.contains('\0') {
+            return Err(anyhow!("invalid environment variable name {name:?}").into());
+        }
+
+        let Some(id) = source.strip_prefix("info:").filter(|id| !id.is_empty()) else {
+            return Err(anyhow!("invalid source {source:?}, expected info:<id or tag>").into());
+        };
+
+        let id = match id.parse::<i32>() {
+            Ok(id) => id.into(),
+            Err(_) => id.into(),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            id,
+        })
+    }
+}
+
+/// Command executed with protected information injected into its environment.
+///
+/// Secrets are resolved right before spawning the child process, which inherits the standard
+/// streams and the rest of the environment. They're never written to disk nor logged.
+///
+/// On Unix, signals received by the current process (i.e. `SIGINT`, `SIGTERM`, `SIGHUP`,
+/// `SIGQUIT`, `SIGUSR1` and `SIGUSR2`) are forwarded to the child while it runs.
+///
+/// # Example
+///
+/// ```no_run
+/// use senhasegura_rs::{ExecCommand, SenhaseguraClient};
+///
+/// # tokio_test::block_on(async {
+/// let base_url = "https://senhasegura.acme.com".parse()?;
+///
+/// let client = SenhaseguraClient::builder(base_url, "client_id", "client_secret").build()?;
+///
+/// let status = ExecCommand::new("./app")
+///     .secret_env("DB_PASS", "db-prod")
+///     .run(&client)
+///     .await?;
+///
+/// std::process::exit(status.code().unwrap_or(1));
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExecCommand {
+    program: OsString,
+    args: Vec<OsString>,
+
+    env: Vec<EnvMapping>,
+    env_remove: Vec<OsString>,
+}
+
+impl ExecCommand {
+    /// Creates a new command, executing the program.
+    pub fn new(program: impl Into<OsString>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+
+            env: Vec::new(),
+            env_remove: Vec::new(),
+        }
+    }
+
+    /// Adds an argument to the program.
+    pub fn arg(mut self, arg: impl Into<OsString>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Adds arguments to the program.
+    pub fn args(mut self, args: impl IntoIterator<Item = impl Into<OsString>>) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets an environment variable to the content of a protected information item.
+    pub fn secret_env(
+        mut self,
+        name: impl ToString,
+        id: impl Into<ProtectedInformationIdentifier>,
+    ) -> Self {
+        self.env.push(EnvMapping {
+            name: name.to_string(),
+            id: id.into(),
+        });
+        self
+    }
+
+    /// Sets environment variables according to the mappings.
+    pub fn secret_envs(mut self, mappings: impl IntoIterator<Item = EnvMapping>) -> Self {
+        self.env.extend(mappings);
+        self
+    }
+
+    /// Removes an inherited environment variable (e.g. the client secret) from the child.
+    pub fn env_remove(mut self, name: impl Into<OsString>) -> Self {
+        self.env_remove.push(name.into());
+        self
+    }
+
+    /// Resolves the secrets, then spawns the command and waits for it to exit.
+    ///
+    /// Fails without spawning the command if any of the secrets can't be resolved.
+    #[tracing::instrument(level = "info", skip_all, fields(program = ?self.program), err)]
+    pub async fn run<T>(self, client: &T) -> Result<ExitStatus, Error>
+    where
+        T: AccessProtectedInformationApi,
+    {
+        let ids = self.env.iter().map(|mapping| mapping.id.clone()).collect();
+
+        let mut results = client
+            .access_protected_information_batch(ids, MAX_CONCURRENCY)
+            .await;
+
+        let mut command = tokio::process::Command::new(&self.program);
+        command.args(&self.args);
+
+        for name in &self.env_remove {
+            command.env_remove(name);
+        }
+
+        for mapping in &self.env {
+            match results.get(&mapping.id) {
+                Some(Ok(response)) => {
+                    command.env(&mapping.name, &response.info.content);
+                }
+                _ => {
+                    tracing::error!(name = %mapping.name, id = %mapping.id, "failed to resolve secret");
+
+                    return Err(match results.remove(&mapping.id) {
+                        Some(Err(e)) => e,
+                        _ => anyhow!("protected information {} wasn't resolved", mapping.id).into(),
+                    });
+                }
+            }
+        }
+
+        let mut child = command.spawn()?;
+
+        tracing::info!(pid = child.id(), "spawned command");
+
+        let status = wait(&mut child).await?;
+
+        tracing::info!(%status, "command exited");
+
+        Ok(status)
+    }
+}
+
+/// Waits for the child to exit, forwarding signals to it.
+#[cfg(unix)]
+async fn wait(child: &mut tokio::process::Child) -> Result<ExitStatus, Error> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let kinds = [
+        SignalKind::interrupt(),
+        SignalKind::terminate(),
+        SignalKind::hangup(),
+        SignalKind::quit(),
+        SignalKind::user_defined1(),
+        SignalKind::user_defined2(),
+    ];
+
+    let mut signals = kinds
+        .iter()
+        .map(|kind| signal(*kind).map(|signal| (*kind, signal)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    loop {
+        let received = futures::future::select_all(signals.iter_mut().map(|(kind, signal)| {
+            Box::pin(async move {
+                signal.recv().await;
+                *kind
+            })
+        }));
+
+        tokio::select! {
+            status = child.wait() => return Ok(status?),
+            (kind, _, _) = received => {
+                let Some(pid) = child.id() else {
+                    continue;
+                };
+
+                tracing::debug!(signal = kind.as_raw_value(), pid, "forwarding signal");
+
+                // SAFETY: `kill` has no memory safety preconditions.
+                unsafe {
+                    libc::kill(pid as libc::pid_t, kind.as_raw_value());
+                }
+            }
+        }
+    }
+}
+
+/// Waits for the child to exit.
+#[cfg(not(unix))]
+async fn wait(child: &mut tokio::process::Child) -> Result<ExitStatus, Error> {
+    Ok(child.wait().await?)
+}
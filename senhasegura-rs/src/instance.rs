@@ -0,0 +1,333 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use tokio::sync::Mutex;
+use url::Url;
+
+use crate::{AuthContext, Error, OAuth2Client};
+
+#[cfg(feature = "retry")]
+pub(crate) type HttpClient = reqwest_middleware::ClientWithMiddleware;
+#[cfg(not(feature = "retry"))]
+pub(crate) type HttpClient = reqwest::Client;
+
+/// Senhasegura instance (e.g. primary or DR cluster), with its own OAuth2 token context.
+#[derive(Debug)]
+pub(crate) struct Instance {
+    pub(crate) base_url: Url,
+
+    pub(crate) oauth2_client: OAuth2Client,
+    pub(crate) auth_ctx: Mutex<Option<AuthContext>>,
+
+    healthy: AtomicBool,
+}
+
+impl Instance {
+    pub(crate) fn new(
+        base_url: Url,
+        client_id: impl ToString,
+        client_secret: impl ToString,
+    ) -> Result<Self, Error> {
+        let base_url = {
+            let mut base_url = base_url;
+
+            let mut path = base_url.path().to_string();
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+            base_url.set_path(&path);
+
+            base_url
+        };
+
+        let oauth2_client =
+            oauth2::basic::BasicClient::new(oauth2::ClientId::new(client_id.to_string()))
+                .set_client_secret(oauth2::ClientSecret::new(client_secret.to_string()))
+                .set_token_uri(oauth2::TokenUrl::from_url(
+                    base_url.join("iso/oauth2/token")?,
+                ))
+                .set_auth_type(oauth2::AuthType::RequestBody);
+
+        Ok(Self {
+            base_url,
+
+            oauth2_client,
+            auth_ctx: Default::default(),
+
+            healthy: AtomicBool::new(true),
+        })
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+}
+
+/// Instances of the client, in order of priority, of which one is active (i.e. serves requests).
+#[derive(Debug)]
+pub(crate) struct Instances {
+    instances: Vec<Instance>,
+    active: AtomicUsize,
+
+    #[cfg(feature = "failover")]
+    policy: FailoverPolicy,
+    #[cfg(feature = "failover")]
+    last_probe: std::sync::Mutex<std::time::Instant>,
+    #[cfg(feature = "failover")]
+    probing: AtomicBool,
+}
+
+impl Instances {
+    pub(crate) fn new(instances: Vec<Instance>) -> Self {
+        Self {
+            instances,
+            active: AtomicUsize::new(0),
+
+            #[cfg(feature = "failover")]
+            policy: Default::default(),
+            #[cfg(feature = "failover")]
+            last_probe: std::sync::Mutex::new(std::time::Instant::now()),
+            #[cfg(feature = "failover")]
+            probing: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Instance {
+        &self.instances[index]
+    }
+
+    /// Returns the indexes of the instances to try a request on, in order: the active instance,
+    /// then the healthy ones and the unhealthy ones last, in order of priority.
+    pub(crate) fn candidates(&self) -> Vec<usize> {
+        let active = self.active.load(Ordering::Relaxed);
+
+        let mut candidates = vec![active];
+
+        for healthy in [true, false] {
+            candidates.extend(
+                (0..self.instances.len())
+                    .filter(|&index| index != active && self.get(index).is_healthy() == healthy),
+            );
+        }
+
+        candidates
+    }
+
+    /// Marks the instance as healthy and, since it served a request, active.
+    pub(crate) fn succeeded(&self, index: usize) {
+        let instance = self.get(index);
+
+        instance.healthy.store(true, Ordering::Relaxed);
+
+        let active = self.active.swap(index, Ordering::Relaxed);
+        if active != index {
+            tracing::warn!(base_url = %instance.base_url, "failed over to instance");
+        }
+    }
+
+    /// Marks the instance as unhealthy, since it's unavailable.
+    pub(crate) fn failed(&self, index: usize, error: &Error) {
+        let instance = self.get(index);
+
+        instance.healthy.store(false, Ordering::Relaxed);
+
+        tracing::warn!(base_url = %instance.base_url, %error, "instance is unavailable");
+    }
+}
+
+/// Failover policy between many Senhasegura instances (e.g. primary and DR clusters).
+///
+/// Requests are served by the active instance, which is initially the base URL of the client. If
+/// it's unavailable (see [Error::is_unavailable]), the request is retried on the other instances,
+/// in order of priority (i.e. the order they were added to the builder), and the first one to
+/// serve it becomes the active instance (i.e. the active instance is sticky).
+///
+/// Requests which aren't idempotent (i.e. creating protected information) are only retried on
+/// the other instances if they never reached the instance (e.g. authentication or connection
+/// errors), so that they aren't served twice (e.g. after timing out).
+///
+/// While an instance other than the one of highest priority is active, or any instance is
+/// unhealthy, instances are probed on the health check interval (by requesting a new access token,
+/// in the background of requests). With fail-back, the client switches back to the instance of
+/// highest priority which is healthy again.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use senhasegura_rs::{FailoverPolicy, SenhaseguraClient};
+///
+/// let client = SenhaseguraClient::builder(
+///     "https://senhasegura.acme.com".parse()?,
+///     "client_id",
+///     "client_secret",
+/// )
+/// .failover_instance("https://senhasegura-dr.acme.com".parse()?)
+/// .failover_policy(FailoverPolicy {
+///     health_check_interval: Duration::from_secs(10),
+///     ..Default::default()
+/// })
+/// .build()?;
+///
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+#[cfg(feature = "failover")]
+#[derive(Clone, Debug)]
+pub struct FailoverPolicy {
+    /// Interval between health check probes.
+    pub health_check_interval: std::time::Duration,
+
+    /// Flag to indicate whether to switch back to instances of higher priority once they're
+    /// healthy again.
+    pub fail_back: bool,
+}
+
+#[cfg(feature = "failover")]
+impl Default for FailoverPolicy {
+    fn default() -> Self {
+        Self {
+            health_check_interval: std::time::Duration::from_secs(30),
+            fail_back: true,
+        }
+    }
+}
+
+#[cfg(feature = "failover")]
+tokio::task_local! {
+    static SERVED_BY: std::cell::RefCell<Option<Url>>;
+}
+
+/// Runs the future, returning its output along with the base URL of the instance which served the
+/// last API request made within it (if any).
+///
+/// Responses served otherwise (e.g. from a cache or snapshot) and errors which weren't served by
+/// an instance (e.g. transport errors) don't count, nor do requests spawned onto other tasks.
+///
+/// # Example
+///
+/// ```no_run
+/// use senhasegura_rs::{with_served_by, AccessProtectedInformationApi, SenhaseguraClient};
+///
+/// # async fn example(client: SenhaseguraClient) -> Result<(), anyhow::Error> {
+/// let (response, served_by) = with_served_by(client.access_protected_information(28)).await;
+///
+/// println!("{} served by {served_by:?}", response?.info.id);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "failover")]
+pub async fn with_served_by<F>(future: F) -> (F::Output, Option<Url>)
+where
+    F: std::future::Future,
+{
+    SERVED_BY
+        .scope(Default::default(), async {
+            let output = future.await;
+
+            (output, SERVED_BY.with(|served_by| served_by.take()))
+        })
+        .await
+}
+
+/// Records the instance which served a request, if within [with_served_by].
+#[cfg(feature = "failover")]
+pub(crate) fn record_served_by(base_url: &Url) {
+    let _ = SERVED_BY.try_with(|served_by| served_by.replace(Some(base_url.clone())));
+}
+
+#[cfg(feature = "failover")]
+impl Instances {
+    pub(crate) fn with_policy(mut self, policy: FailoverPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Probes the instances in the background, if due.
+    ///
+    /// Probes are only due while an instance other than the one of highest priority is active, or
+    /// any instance is unhealthy. They require a Tokio runtime, and are skipped otherwise.
+    pub(crate) fn probe_if_due(self: &std::sync::Arc<Self>, http_client: &HttpClient) {
+        let active = self.active.load(Ordering::Relaxed);
+
+        if active == 0 && self.instances.iter().all(Instance::is_healthy) {
+            return;
+        }
+
+        {
+            let mut last_probe = self.last_probe.lock().unwrap();
+
+            if last_probe.elapsed() < self.policy.health_check_interval {
+                return;
+            }
+
+            *last_probe = std::time::Instant::now();
+        }
+
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        if self.probing.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        let instances = self.clone();
+        let http_client = http_client.clone();
+
+        let probe = async move {
+            instances.probe(&http_client).await;
+            instances.probing.store(false, Ordering::Relaxed);
+        };
+
+        // The telemetry of the operation which triggered the probes records them.
+        #[cfg(feature = "otel")]
+        let probe = opentelemetry::context::FutureExt::with_context(
+            probe,
+            opentelemetry::Context::current(),
+        );
+
+        handle.spawn(probe);
+    }
+
+    /// Probes the unhealthy instances and those of higher priority than the active one.
+    async fn probe(&self, http_client: &HttpClient) {
+        let active = self.active.load(Ordering::Relaxed);
+
+        for (index, instance) in self.instances.iter().enumerate() {
+            if index >= active && instance.is_healthy() {
+                continue;
+            }
+
+            let probe = instance.authenticate(http_client, true);
+
+            #[cfg(feature = "otel")]
+            let cx = crate::otel::start_probe(&instance.base_url);
+            #[cfg(feature = "otel")]
+            let probe = opentelemetry::context::FutureExt::with_context(probe, cx.clone());
+
+            let result = probe.await;
+
+            #[cfg(feature = "otel")]
+            crate::otel::finish_probe(cx, &result);
+
+            match result {
+                Ok(_) => {
+                    tracing::info!(base_url = %instance.base_url, "instance is healthy");
+
+                    instance.healthy.store(true, Ordering::Relaxed);
+
+                    if self.policy.fail_back && index < self.active.load(Ordering::Relaxed) {
+                        tracing::info!(base_url = %instance.base_url, "failed back to instance");
+
+                        self.active.store(index, Ordering::Relaxed);
+                    }
+                }
+                Err(e) => {
+                    tracing::debug!(base_url = %instance.base_url, error = %e, "instance is still unavailable");
+
+                    instance.healthy.store(false, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
@@ -28,12 +28,68 @@
 mod auth;
 use auth::*;
 
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "cache")]
+pub use cache::*;
+
+#[cfg(feature = "cassette")]
+mod cassette;
+#[cfg(feature = "cassette")]
+pub use cassette::*;
+
 mod common;
 pub use common::*;
 
+mod config;
+pub use config::*;
+
+mod dynamic;
+pub use dynamic::*;
+
 mod error;
 pub use error::*;
 
+#[cfg(feature = "exec")]
+mod exec;
+#[cfg(feature = "exec")]
+pub use exec::*;
+
+mod instance;
+use instance::*;
+#[cfg(feature = "failover")]
+pub use instance::{with_served_by, FailoverPolicy};
+
+#[cfg(feature = "otel")]
+mod otel;
+
+#[cfg(feature = "pool")]
+mod pool;
+#[cfg(feature = "pool")]
+pub use pool::*;
+
+#[cfg(feature = "snapshot")]
+mod snapshot;
+#[cfg(feature = "snapshot")]
+pub use snapshot::*;
+
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "template")]
+pub use template::*;
+
+/// Test utilities.
+///
+/// Provides [FakeSenhasegura](testing::FakeSenhasegura), an in-memory implementation of the API
+/// traits, so that code built on top of this crate may be tested without a network.
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "watch")]
+mod watcher;
+#[cfg(feature = "watch")]
+pub use watcher::*;
+
 /// PAM Core APIs.
 ///
 /// See [PAM Core APIs](https://docs.senhasegura.io/docs/a2a-apis-pam-core).
@@ -42,7 +98,6 @@ pub use pam_core::*;
 
 use std::sync::Arc;
 
-use tokio::sync::Mutex;
 use url::Url;
 
 /// Trait to interact with Senhasegura APIs.
@@ -56,16 +111,14 @@ impl<T> SenhaseguraApi for T where T: PAMCoreApi {}
 #[derive(Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+#[cfg_attr(feature = "pyo3", pyo3::pyclass(frozen, module = "senhasegura"))]
 pub struct SenhaseguraClient {
-    base_url: Url,
+    http_client: HttpClient,
 
-    #[cfg(feature = "retry")]
-    http_client: reqwest_middleware::ClientWithMiddleware,
-    #[cfg(not(feature = "retry"))]
-    http_client: reqwest::Client,
+    instances: Arc<Instances>,
 
-    oauth2_client: OAuth2Client,
-    auth_ctx: Arc<Mutex<Option<AuthContext>>>,
+    #[cfg(feature = "otel")]
+    telemetry: Arc<otel::Telemetry>,
 }
 
 impl SenhaseguraClient {
@@ -86,21 +139,135 @@ impl SenhaseguraClient {
         TRes: serde::de::DeserializeOwned + std::fmt::Debug,
     >(
         &self,
+        operation: Operation,
         method: http::method::Method,
         path: TPath,
         data: Option<TReq>,
     ) -> Result<TRes, Error> {
-        let access_token = self.authenticate().await?;
+        #[cfg(feature = "otel")]
+        let telemetry = self.telemetry.start(operation);
+
+        let request = self.do_failover_api_request(method, path.as_ref(), data);
+
+        #[cfg(feature = "otel")]
+        let request = opentelemetry::context::FutureExt::with_context(request, telemetry.context());
+
+        let (result, served_by) = request.await;
+
+        #[cfg(feature = "failover")]
+        if let Some(served_by) = &served_by {
+            instance::record_served_by(served_by);
+        }
+
+        #[cfg(feature = "otel")]
+        telemetry.finish(&result, served_by.as_ref());
+
+        #[cfg(not(any(feature = "failover", feature = "otel")))]
+        let _ = served_by;
+
+        result
+    }
+
+    /// Performs an authenticated API request, failing over between instances, returning the result
+    /// along with the base URL of the instance which served it (if any).
+    async fn do_failover_api_request<TReq, TRes>(
+        &self,
+        method: http::method::Method,
+        path: &str,
+        data: Option<TReq>,
+    ) -> (Result<TRes, Error>, Option<Url>)
+    where
+        TReq: serde::ser::Serialize,
+        TRes: serde::de::DeserializeOwned,
+    {
+        #[cfg(feature = "failover")]
+        self.instances.probe_if_due(&self.http_client);
+
+        let mut candidates = self.instances.candidates().into_iter().peekable();
+
+        while let Some(index) = candidates.next() {
+            let instance = self.instances.get(index);
+
+            // Whether the request was sent to the instance (i.e. it was authenticated).
+            let mut sent = false;
+
+            let result = match instance.authenticate(&self.http_client, false).await {
+                Ok(access_token) => {
+                    sent = true;
+
+                    self.do_instance_api_request::<_, TRes>(
+                        instance,
+                        &access_token,
+                        method.clone(),
+                        path.as_ref(),
+                        data.as_ref(),
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(response) => {
+                    self.instances.succeeded(index);
+
+                    return (Ok(response), Some(instance.base_url.clone()));
+                }
+                Err(e) => {
+                    // Requests which aren't idempotent (i.e. POST) may have been served before failing
+                    // (e.g. timing out after the item was created), thus they're only failed over if
+                    // they never reached the instance (i.e. authentication or connection errors).
+                    let may_fail_over = if method.is_idempotent() || !sent {
+                        e.is_unavailable()
+                    } else {
+                        matches!(&e, Error::Transport(e) if e.is_connect())
+                    };
+
+                    if !may_fail_over || candidates.peek().is_none() {
+                        // API errors are served by the instance, unlike other errors.
+                        let served_by =
+                            matches!(e, Error::Api(_)).then(|| instance.base_url.clone());
+
+                        return (Err(e), served_by);
+                    }
+
+                    self.instances.failed(index, &e);
+                }
+            }
+        }
+
+        unreachable!("there's always at least one instance")
+    }
 
-        let url = self.base_url.join(path.as_ref())?;
+    /// Performs an authenticated API request to the instance.
+    async fn do_instance_api_request<TReq, TRes>(
+        &self,
+        instance: &Instance,
+        access_token: &oauth2::AccessToken,
+        method: http::method::Method,
+        path: &str,
+        data: Option<&TReq>,
+    ) -> Result<TRes, Error>
+    where
+        TReq: serde::ser::Serialize,
+        TRes: serde::de::DeserializeOwned,
+    {
+        let url = instance.base_url.join(path)?;
 
         let mut req = self
             .http_client
             .request(method, url)
             .bearer_auth(access_token.secret());
 
+        #[cfg(feature = "otel")]
+        {
+            req = req.with_extension(otel::ApiRequest {
+                base_url: instance.base_url.clone(),
+            });
+        }
+
         if let Some(data) = data {
-            req = req.json(&data);
+            req = req.json(data);
         }
 
         let response = req.send().await?;
@@ -110,9 +277,16 @@ impl SenhaseguraClient {
 
             Ok(response)
         } else {
-            let api_error = response.json().await?;
-
-            Err(Error::Api(api_error))
+            let status = response.status();
+
+            match response.json().await {
+                Ok(api_error) => Err(Error::Api(api_error)),
+                // Server errors may come from elsewhere (e.g. a gateway), without an API error body.
+                Err(e) if status.is_server_error() && e.is_decode() => {
+                    Err(Error::Api(ApiError::from_status(status, e)))
+                }
+                Err(e) => Err(e.into()),
+            }
         }
     }
 }
@@ -130,6 +304,17 @@ pub struct SenhaseguraClientBuilder {
     base_retry_delay_secs: Option<u32>,
     #[cfg(feature = "retry")]
     max_n_retries: Option<u32>,
+
+    #[cfg(feature = "cassette")]
+    cassette: Option<Cassette>,
+
+    #[cfg(feature = "failover")]
+    failover_base_urls: Vec<Url>,
+    #[cfg(feature = "failover")]
+    failover_policy: FailoverPolicy,
+
+    #[cfg(feature = "pool")]
+    http_client: Option<reqwest::Client>,
 }
 
 impl SenhaseguraClientBuilder {
@@ -147,6 +332,17 @@ impl SenhaseguraClientBuilder {
             base_retry_delay_secs: None,
             #[cfg(feature = "retry")]
             max_n_retries: None,
+
+            #[cfg(feature = "cassette")]
+            cassette: None,
+
+            #[cfg(feature = "failover")]
+            failover_base_urls: Vec::new(),
+            #[cfg(feature = "failover")]
+            failover_policy: Default::default(),
+
+            #[cfg(feature = "pool")]
+            http_client: None,
         }
     }
 
@@ -176,27 +372,72 @@ impl SenhaseguraClientBuilder {
         self.base_retry_delay_secs(0).max_n_retries(0)
     }
 
+    /// Sets the cassette to record / replay HTTP interactions with.
+    #[cfg(feature = "cassette")]
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Adds a failover instance (i.e. its base URL), of lower priority than the base URL and the
+    /// previously added instances.
+    ///
+    /// Instances share the client credentials, but each has its own access token.
+    #[cfg(feature = "failover")]
+    pub fn failover_instance(mut self, base_url: Url) -> Self {
+        self.failover_base_urls.push(base_url);
+        self
+    }
+
+    /// Sets the failover policy between the instances.
+    #[cfg(feature = "failover")]
+    pub fn failover_policy(mut self, failover_policy: FailoverPolicy) -> Self {
+        self.failover_policy = failover_policy;
+        self
+    }
+
+    /// Sets the underlying HTTP client (e.g. to share its connection pool), whose request timeout
+    /// is used instead of the one of the builder.
+    #[cfg(feature = "pool")]
+    pub(crate) fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the Senhasegura API client.
     pub fn build(self) -> Result<SenhaseguraClient, Error> {
-        let base_url = {
-            let mut base_url = self.base_url;
+        #[allow(unused_mut)]
+        let mut instances = vec![Instance::new(
+            self.base_url,
+            &self.client_id,
+            &self.client_secret,
+        )?];
+
+        #[cfg(feature = "failover")]
+        for base_url in self.failover_base_urls {
+            instances.push(Instance::new(
+                base_url,
+                &self.client_id,
+                &self.client_secret,
+            )?);
+        }
 
-            let mut path = base_url.path().to_string();
-            if !path.ends_with('/') {
-                path.push('/');
-            }
-            base_url.set_path(&path);
+        let instances = Instances::new(instances);
+
+        #[cfg(feature = "failover")]
+        let instances = instances.with_policy(self.failover_policy);
 
-            base_url
+        #[cfg(feature = "pool")]
+        let http_client = match self.http_client {
+            Some(http_client) => http_client,
+            None => new_http_client(self.request_timeout)?,
         };
 
-        let http_client = reqwest::Client::builder()
-            .use_rustls_tls()
-            .timeout(
-                self.request_timeout
-                    .unwrap_or(std::time::Duration::from_secs(10)),
-            )
-            .build()?;
+        #[cfg(not(feature = "pool"))]
+        let http_client = new_http_client(self.request_timeout)?;
+
+        #[cfg(feature = "otel")]
+        let telemetry = Arc::new(otel::Telemetry::new());
 
         #[cfg(feature = "retry")]
         let http_client = {
@@ -207,32 +448,47 @@ impl SenhaseguraClientBuilder {
                 .base(self.base_retry_delay_secs.unwrap_or(2))
                 .build_with_max_retries(self.max_n_retries.unwrap_or(3));
 
-            reqwest_middleware::ClientBuilder::new(http_client)
-                .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-                .build()
-        };
+            let builder = reqwest_middleware::ClientBuilder::new(http_client)
+                .with(RetryTransientMiddleware::new_with_policy(retry_policy));
 
-        let oauth2_client = oauth2::basic::BasicClient::new(oauth2::ClientId::new(self.client_id))
-            .set_client_secret(oauth2::ClientSecret::new(self.client_secret))
-            .set_token_uri(oauth2::TokenUrl::from_url(
-                base_url.join("iso/oauth2/token")?,
-            ))
-            .set_auth_type(oauth2::AuthType::RequestBody);
+            #[cfg(feature = "otel")]
+            let builder = builder.with(otel::TelemetryMiddleware::new(telemetry.clone()));
 
-        Ok(SenhaseguraClient {
-            base_url,
+            #[cfg(feature = "cassette")]
+            let builder = match self.cassette {
+                Some(cassette) => builder.with(cassette),
+                None => builder,
+            };
+
+            builder.build()
+        };
 
+        Ok(SenhaseguraClient {
             http_client,
 
-            oauth2_client,
-            auth_ctx: Default::default(),
+            instances: Arc::new(instances),
+
+            #[cfg(feature = "otel")]
+            telemetry,
         })
     }
 }
 
+/// Creates a new HTTP client, with its own connection pool.
+fn new_http_client(
+    request_timeout: Option<std::time::Duration>,
+) -> Result<reqwest::Client, reqwest::Error> {
+    reqwest::Client::builder()
+        .use_rustls_tls()
+        .timeout(request_timeout.unwrap_or(std::time::Duration::from_secs(10)))
+        .build()
+}
+
 #[cfg(feature = "blocking")]
 impl SenhaseguraClient {
-    pub(crate) fn async_runtime(&self) -> Result<tokio::runtime::Handle, Error> {
+    /// Returns the handle of the async runtime which blocking methods run on (e.g. so that FFI
+    /// bindings may also spawn requests on it).
+    pub fn async_runtime(&self) -> Result<tokio::runtime::Handle, Error> {
         use once_cell::sync::OnceCell;
 
         static RUNTIME: OnceCell<tokio::runtime::Runtime> = OnceCell::new();
@@ -310,7 +566,103 @@ mod senhasegura_js {
     }
 }
 
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::{prelude::*, types::PyTuple, BoundObject};
+
+    use super::*;
+
+    #[pymethods]
+    impl SenhaseguraClient {
+        /// Creates a new Senhasegura API client.
+        ///
+        /// The request timeout and the base delay of the exponential backoff retry policy are in
+        /// seconds.
+        #[new]
+        #[pyo3(signature = (
+            base_url,
+            client_id,
+            client_secret,
+            *,
+            request_timeout = None,
+            base_retry_delay = None,
+            max_n_retries = None
+        ))]
+        fn py_new(
+            base_url: &str,
+            client_id: String,
+            client_secret: String,
+            request_timeout: Option<u32>,
+            base_retry_delay: Option<u32>,
+            max_n_retries: Option<u32>,
+        ) -> Result<Self, Error> {
+            let base_url = Url::parse(base_url)?;
+
+            let mut builder = SenhaseguraClientBuilder::new(base_url, client_id, client_secret);
+
+            if let Some(request_timeout) = request_timeout {
+                builder =
+                    builder.request_timeout(std::time::Duration::from_secs(request_timeout as u64));
+            }
+
+            if let Some(base_retry_delay_secs) = base_retry_delay {
+                builder = builder.base_retry_delay_secs(base_retry_delay_secs);
+            }
+
+            if let Some(max_n_retries) = max_n_retries {
+                builder = builder.max_n_retries(max_n_retries);
+            }
+
+            builder.build()
+        }
+    }
+
+    /// Calls the blocking method in the default executor of the running event loop, returning an
+    /// awaitable of its result.
+    ///
+    /// Requests thus run on the executor's Python threads (releasing the GIL), instead of
+    /// completing asyncio futures from the async runtime's threads, which may crash while the
+    /// interpreter is finalizing.
+    pub(crate) fn run_in_executor<'py>(
+        method: Bound<'py, PyAny>,
+        args: impl IntoPyObject<'py, Target = PyTuple>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = method.py();
+
+        let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+
+        let args = args.into_pyobject(py).map_err(Into::into)?.into_bound();
+        let args = [py.None().into_bound(py), method]
+            .into_iter()
+            .chain(args.iter())
+            .collect::<Vec<_>>();
+
+        event_loop.call_method1("run_in_executor", PyTuple::new(py, args)?)
+    }
+
+    /// Senhasegura API client for Python.
+    #[pymodule]
+    fn senhasegura(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        m.add_class::<SenhaseguraClient>()?;
+        m.add_class::<AccessProtectedInformationApiResponse>()?;
+        m.add_class::<AccessProtectedInformationResult>()?;
+        m.add_class::<CreateProtectedInformationApiRequest>()?;
+        m.add_class::<CreateProtectedInformationApiResponse>()?;
+        m.add_class::<CreateProtectedInformationResult>()?;
+        m.add_class::<DisableProtectedInformationApiResponse>()?;
+        m.add_class::<Response>()?;
+        m.add_class::<Exception>()?;
+        m.add_class::<ProtectedInformationExceptionCode>()?;
+
+        add_exceptions(m)
+    }
+}
+
+#[cfg(feature = "pyo3")]
+use senhasegura_py::run_in_executor;
+
 #[cfg(feature = "uniffi")]
+#[allow(unpredictable_function_pointer_comparisons)] // For `uniffi::setup_scaffolding!`
 mod senhasegura_uniffi {
     use super::*;
 
@@ -324,12 +676,82 @@ mod senhasegura_uniffi {
             base_url: String,
             client_id: String,
             client_secret: String,
-        ) -> Result<Arc<SenhaseguraClient>, Error> {
-            let client = SenhaseguraClientBuilder::new(base_url.parse()?, client_id, client_secret)
-                .build()?;
+        ) -> Result<Arc<SenhaseguraClient>, SenhaseguraError> {
+            build_client(base_url, client_id, client_secret, Default::default())
+                .map(Arc::new)
+                .map_err(Into::into)
+        }
+
+        /// Creates a new Senhasegura API client, with the given options.
+        #[uniffi::constructor]
+        fn with_options(
+            base_url: String,
+            client_id: String,
+            client_secret: String,
+            options: SenhaseguraClientOptions,
+        ) -> Result<Arc<SenhaseguraClient>, SenhaseguraError> {
+            build_client(base_url, client_id, client_secret, options)
+                .map(Arc::new)
+                .map_err(Into::into)
+        }
+    }
 
-            Ok(Arc::new(client))
+    /// Builds a client with the given options.
+    fn build_client(
+        base_url: String,
+        client_id: String,
+        client_secret: String,
+        options: SenhaseguraClientOptions,
+    ) -> Result<SenhaseguraClient, Error> {
+        let mut builder =
+            SenhaseguraClientBuilder::new(base_url.parse()?, client_id, client_secret);
+
+        if let Some(request_timeout) = options.request_timeout {
+            builder = builder.request_timeout(request_timeout);
         }
+
+        #[cfg(feature = "retry")]
+        if let Some(base_retry_delay_secs) = options.base_retry_delay_secs {
+            builder = builder.base_retry_delay_secs(base_retry_delay_secs);
+        }
+
+        #[cfg(feature = "retry")]
+        if let Some(max_n_retries) = options.max_n_retries {
+            builder = builder.max_n_retries(max_n_retries);
+        }
+
+        builder.build()
+    }
+
+    /// Senhasegura API client options.
+    ///
+    /// Mirrors [`SenhaseguraClientBuilder`], where unset options keep their defaults.
+    #[derive(uniffi::Record, Clone, Debug, Default)]
+    pub struct SenhaseguraClientOptions {
+        /// Request timeout.
+        #[uniffi(default = None)]
+        pub request_timeout: Option<std::time::Duration>,
+
+        /// Base retry delay, in seconds.
+        #[uniffi(default = None)]
+        pub base_retry_delay_secs: Option<u32>,
+
+        /// Maximum number of retries.
+        #[uniffi(default = None)]
+        pub max_n_retries: Option<u32>,
+    }
+
+    /// Runs the API request on the async runtime of blocking methods, since foreign executors
+    /// (e.g. Kotlin coroutines, Swift tasks) don't provide the Tokio reactor it relies on.
+    pub(crate) async fn spawn_request<T: Send + 'static>(
+        client: Arc<SenhaseguraClient>,
+        request: impl std::future::Future<Output = Result<T, Error>> + Send + 'static,
+    ) -> Result<T, Error> {
+        client
+            .async_runtime()?
+            .spawn(request)
+            .await
+            .map_err(|e| Error::Other(e.into()))?
     }
 }
 
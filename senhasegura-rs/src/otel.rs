@@ -0,0 +1,362 @@
+use std::{sync::Arc, time::Instant};
+
+use async_trait::async_trait;
+use http::Extensions;
+use opentelemetry::{
+    global::{self, BoxedTracer},
+    metrics::{Counter, Histogram},
+    trace::{SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use opentelemetry_http::HeaderInjector;
+use reqwest_middleware::{Middleware, Next};
+use url::Url;
+
+use crate::{Error, Operation};
+
+/// Instrumentation scope of spans and metrics.
+const SCOPE: &str = "senhasegura-rs";
+
+/// OpenTelemetry instruments of a client.
+///
+/// Spans and metrics are recorded through the global tracer and meter providers, thus they should
+/// be installed before the client is built. The trace context is propagated through the global text
+/// map propagator (e.g. `TraceContextPropagator`, for W3C trace context).
+pub(crate) struct Telemetry {
+    tracer: BoxedTracer,
+
+    requests: Counter<u64>,
+    request_duration: Histogram<f64>,
+    errors: Counter<u64>,
+    token_refreshes: Counter<u64>,
+    retries: Counter<u64>,
+}
+
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+/// Context value of an operation in progress.
+struct OperationContext {
+    telemetry: Arc<Telemetry>,
+    operation: Operation,
+}
+
+/// Attempt number of a request, stored in its extensions.
+#[derive(Clone, Copy)]
+struct Attempt(u32);
+
+/// Marks an API request (i.e. not an OAuth2 token request), stored in its extensions.
+#[derive(Clone)]
+pub(crate) struct ApiRequest {
+    /// Base URL of the instance which the request is made to.
+    pub(crate) base_url: Url,
+}
+
+impl Telemetry {
+    pub(crate) fn new() -> Self {
+        let meter = global::meter(SCOPE);
+
+        Self {
+            tracer: global::tracer(SCOPE),
+
+            requests: meter
+                .u64_counter("senhasegura.client.requests")
+                .with_description("Number of API operations.")
+                .build(),
+            request_duration: meter
+                .f64_histogram("senhasegura.client.request.duration")
+                .with_description("Duration of API operations, including retries and failover.")
+                .with_unit("s")
+                .build(),
+            errors: meter
+                .u64_counter("senhasegura.client.errors")
+                .with_description("Number of failed API operations, by error category.")
+                .build(),
+            token_refreshes: meter
+                .u64_counter("senhasegura.client.token.refreshes")
+                .with_description(
+                    "Number of OAuth2 access token requests, including those of failover probes.",
+                )
+                .build(),
+            retries: meter
+                .u64_counter("senhasegura.client.retries")
+                .with_description("Number of retried HTTP requests.")
+                .build(),
+        }
+    }
+
+    /// Starts the span of the operation.
+    pub(crate) fn start(self: &Arc<Self>, operation: Operation) -> OperationTelemetry {
+        let mut attributes = vec![KeyValue::new("senhasegura.operation", operation.name)];
+        if let Some(kind) = operation.identifier_kind {
+            attributes.push(KeyValue::new("senhasegura.identifier.kind", kind));
+        }
+
+        let span = self
+            .tracer
+            .span_builder(operation.name)
+            .with_kind(SpanKind::Internal)
+            .with_attributes(attributes)
+            .start(&self.tracer);
+
+        let cx = Context::current_with_span(span).with_value(OperationContext {
+            telemetry: self.clone(),
+            operation,
+        });
+
+        OperationTelemetry {
+            cx,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+/// Telemetry of an operation in progress.
+pub(crate) struct OperationTelemetry {
+    cx: Context,
+    started_at: Instant,
+}
+
+impl OperationTelemetry {
+    /// Returns the context which the operation should run within.
+    pub(crate) fn context(&self) -> Context {
+        self.cx.clone()
+    }
+
+    /// Ends the span of the operation, recording its metrics and the instance which served it.
+    pub(crate) fn finish<T>(self, result: &Result<T, Error>, served_by: Option<&Url>) {
+        let Some(OperationContext {
+            telemetry,
+            operation,
+        }) = self.cx.get::<OperationContext>()
+        else {
+            return;
+        };
+
+        let span = self.cx.span();
+
+        let mut attributes = vec![KeyValue::new("senhasegura.operation", operation.name)];
+
+        if let Some(served_by) = served_by {
+            span.set_attribute(KeyValue::new("senhasegura.instance", served_by.to_string()));
+        }
+
+        if let Err(e) = result {
+            let error_type = match e {
+                Error::Api(_) => "api",
+                Error::Transport(_) => "transport",
+                Error::Other(_) => "other",
+            };
+
+            attributes.push(KeyValue::new("error.type", error_type));
+
+            let mut error_attributes = attributes.clone();
+
+            if let Error::Api(api_error) = e {
+                if let Some(exception) = &api_error.exception {
+                    let code = i64::from(u16::from(exception.code.clone()));
+
+                    span.set_attribute(KeyValue::new("senhasegura.exception.code", code));
+                    error_attributes.push(KeyValue::new("senhasegura.exception.code", code));
+                }
+            }
+
+            span.set_attribute(KeyValue::new("error.type", error_type));
+            span.set_status(Status::error(e.to_string()));
+
+            telemetry.errors.add(1, &error_attributes);
+        }
+
+        telemetry.requests.add(1, &attributes);
+        telemetry
+            .request_duration
+            .record(self.started_at.elapsed().as_secs_f64(), &attributes);
+
+        span.end();
+    }
+}
+
+/// Records an OAuth2 access token request, made within an operation.
+pub(crate) fn record_token_refresh(base_url: &Url, succeeded: bool) {
+    let cx = Context::current();
+
+    let Some(OperationContext { telemetry, .. }) = cx.get::<OperationContext>() else {
+        return;
+    };
+
+    let mut attributes = vec![KeyValue::new(
+        "server.address",
+        base_url.host_str().unwrap_or_default().to_string(),
+    )];
+    if !succeeded {
+        attributes.push(KeyValue::new("error.type", "other"));
+    }
+
+    telemetry.token_refreshes.add(1, &attributes);
+}
+
+/// Operation of background failover probes, which authenticate against the instances.
+#[cfg(feature = "failover")]
+const PROBE: Operation = Operation {
+    name: "probe_instance",
+    identifier_kind: None,
+    url_template: "",
+};
+
+/// Starts the span of a background failover probe of the instance, returning the context which it
+/// should run within, so that its token refresh is recorded like those of operations.
+///
+/// Probes are traced on their own, rather than within the operation which triggered them (i.e. the
+/// current one), since they may outlive it.
+#[cfg(feature = "failover")]
+pub(crate) fn start_probe(base_url: &Url) -> Context {
+    let cx = Context::current();
+
+    let Some(OperationContext { telemetry, .. }) = cx.get::<OperationContext>() else {
+        return Context::new();
+    };
+
+    let span = telemetry
+        .tracer
+        .span_builder(PROBE.name)
+        .with_kind(SpanKind::Internal)
+        .with_attributes([KeyValue::new("senhasegura.instance", base_url.to_string())])
+        .start_with_context(&telemetry.tracer, &Context::new());
+
+    Context::new().with_span(span).with_value(OperationContext {
+        telemetry: telemetry.clone(),
+        operation: PROBE,
+    })
+}
+
+/// Ends the span of a background failover probe.
+#[cfg(feature = "failover")]
+pub(crate) fn finish_probe<T>(cx: Context, result: &Result<T, Error>) {
+    let span = cx.span();
+
+    if let Err(e) = result {
+        span.set_status(Status::error(e.to_string()));
+    }
+
+    span.end();
+}
+
+/// Middleware which records a span per HTTP request (i.e. per attempt) with the HTTP semantic
+/// conventions, propagating its W3C trace context.
+pub(crate) struct TelemetryMiddleware {
+    telemetry: Arc<Telemetry>,
+}
+
+impl TelemetryMiddleware {
+    pub(crate) fn new(telemetry: Arc<Telemetry>) -> Self {
+        Self { telemetry }
+    }
+}
+
+#[async_trait]
+impl Middleware for TelemetryMiddleware {
+    async fn handle(
+        &self,
+        mut req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let parent_cx = Context::current();
+        let operation = parent_cx.get::<OperationContext>().map(|cx| cx.operation);
+
+        let attempt = match extensions.get_mut::<Attempt>() {
+            Some(attempt) => {
+                attempt.0 += 1;
+                attempt.0
+            }
+            None => {
+                extensions.insert(Attempt(1));
+                1
+            }
+        };
+
+        let url = req.url();
+
+        let mut attributes = vec![
+            KeyValue::new("http.request.method", req.method().to_string()),
+            KeyValue::new(
+                "server.address",
+                url.host_str().unwrap_or_default().to_string(),
+            ),
+        ];
+
+        // The path of API requests has the identifier (e.g. tag) of the protected information,
+        // thus their template is recorded instead.
+        match (operation, extensions.get::<ApiRequest>()) {
+            (Some(operation), Some(ApiRequest { base_url })) => attributes.push(KeyValue::new(
+                "url.template",
+                format!("{}{}", base_url.path(), operation.url_template),
+            )),
+            _ => attributes.push(KeyValue::new("url.full", url.to_string())),
+        }
+        if let Some(port) = url.port_or_known_default() {
+            attributes.push(KeyValue::new("server.port", i64::from(port)));
+        }
+        if let Some(operation) = operation {
+            attributes.push(KeyValue::new("senhasegura.operation", operation.name));
+        }
+        if attempt > 1 {
+            attributes.push(KeyValue::new(
+                "http.request.resend_count",
+                i64::from(attempt - 1),
+            ));
+
+            let operation = operation
+                .map(|operation| operation.name)
+                .unwrap_or("unknown");
+
+            self.telemetry
+                .retries
+                .add(1, &[KeyValue::new("senhasegura.operation", operation)]);
+        }
+
+        let span = self
+            .telemetry
+            .tracer
+            .span_builder(req.method().to_string())
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes)
+            .start_with_context(&self.telemetry.tracer, &parent_cx);
+
+        let cx = parent_cx.with_span(span);
+        let span = cx.span();
+
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&cx, &mut HeaderInjector(req.headers_mut()))
+        });
+
+        let result = next.run(req, extensions).await;
+
+        match &result {
+            Ok(response) => {
+                let status = response.status();
+
+                span.set_attribute(KeyValue::new(
+                    "http.response.status_code",
+                    i64::from(status.as_u16()),
+                ));
+
+                if status.is_client_error() || status.is_server_error() {
+                    span.set_attribute(KeyValue::new("error.type", status.as_str().to_string()));
+                    span.set_status(Status::error(""));
+                }
+            }
+            Err(e) => {
+                span.set_attribute(KeyValue::new("error.type", "transport"));
+                span.set_status(Status::error(e.to_string()));
+            }
+        }
+
+        span.end();
+
+        result
+    }
+}
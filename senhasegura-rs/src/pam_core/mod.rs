@@ -5,7 +5,7 @@ pub mod protected_information;
 pub use protected_information::*;
 
 /// PAM Core exception codes.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug)]
 #[serde(untagged)]
 pub enum PAMCoreExceptionCode {
     /// Protected information exception code.
@@ -2,14 +2,18 @@ use async_trait::async_trait;
 use http::Method;
 use serde_aux::field_attributes::deserialize_number_from_string;
 
-use crate::{Error, Response, SenhaseguraClient};
+use crate::{Error, Operation, Response, SenhaseguraClient};
 
 use super::ProtectedInformationIdentifier;
 
 /// Access protected information API response.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct AccessProtectedInformationApiResponse {
     /// Response.
     pub response: Response,
@@ -19,9 +23,13 @@ pub struct AccessProtectedInformationApiResponse {
 }
 
 /// Access protected information result (i.e. "info") field.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct AccessProtectedInformationResult {
     /// Protected information item ’s unique identification code.
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -56,9 +64,18 @@ impl AccessProtectedInformationApi for SenhaseguraClient {
         &self,
         id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
     ) -> Result<AccessProtectedInformationApiResponse, Error> {
+        let id = id.into();
+
+        let operation = Operation {
+            name: "access_protected_information",
+            identifier_kind: Some(id.kind()),
+            url_template: "iso/pam/info/{identifier}",
+        };
+
         self.do_api_request(
+            operation,
             Method::GET,
-            format!("iso/pam/info/{}", id.into()),
+            format!("iso/pam/info/{id}"),
             None::<()>,
         )
         .await
@@ -66,7 +83,6 @@ impl AccessProtectedInformationApi for SenhaseguraClient {
 }
 
 #[cfg(feature = "blocking")]
-#[cfg_attr(feature = "uniffi", uniffi::export)]
 impl SenhaseguraClient {
     /// Returns the protected information item.
     pub fn access_protected_information_sync(
@@ -99,3 +115,71 @@ mod senhasegura_js {
         }
     }
 }
+
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::prelude::*;
+
+    use super::*;
+
+    #[pymethods]
+    impl SenhaseguraClient {
+        /// Returns the protected information item.
+        #[pyo3(name = "access_protected_information")]
+        fn py_access_protected_information(
+            &self,
+            py: Python<'_>,
+            id: ProtectedInformationIdentifier,
+        ) -> PyResult<AccessProtectedInformationApiResponse> {
+            py.allow_threads(|| {
+                self.async_runtime()?.block_on(
+                    <Self as AccessProtectedInformationApi>::access_protected_information(self, id),
+                )
+            })
+            .map_err(Into::into)
+        }
+
+        /// Returns the protected information item, asynchronously.
+        #[pyo3(name = "access_protected_information_async")]
+        fn py_access_protected_information_async<'py>(
+            slf: &Bound<'py, Self>,
+            id: ProtectedInformationIdentifier,
+        ) -> PyResult<Bound<'py, PyAny>> {
+            crate::run_in_executor(slf.getattr("access_protected_information")?, (id,))
+        }
+    }
+}
+
+#[cfg(feature = "uniffi")]
+mod senhasegura_uniffi {
+    use std::sync::Arc;
+
+    use crate::SenhaseguraError;
+
+    use super::*;
+
+    #[uniffi::export]
+    impl SenhaseguraClient {
+        /// Returns the protected information item.
+        pub fn access_protected_information_blocking(
+            &self,
+            id: String,
+        ) -> Result<AccessProtectedInformationApiResponse, SenhaseguraError> {
+            self.access_protected_information_sync(id)
+                .map_err(Into::into)
+        }
+
+        /// Returns the protected information item, asynchronously.
+        pub async fn access_protected_information_async(
+            self: Arc<Self>,
+            id: String,
+        ) -> Result<AccessProtectedInformationApiResponse, SenhaseguraError> {
+            crate::spawn_request(self.clone(), async move {
+                <Self as AccessProtectedInformationApi>::access_protected_information(&self, id)
+                    .await
+            })
+            .await
+            .map_err(Into::into)
+        }
+    }
+}
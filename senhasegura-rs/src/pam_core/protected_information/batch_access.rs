@@ -0,0 +1,210 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+
+use crate::{AccessProtectedInformationApiResponse, Error};
+
+use super::{AccessProtectedInformationApi, ProtectedInformationIdentifier};
+
+#[cfg(feature = "blocking")]
+use crate::SenhaseguraClient;
+
+/// Batch access protected information result, by protected information item.
+pub type BatchAccessProtectedInformationResult =
+    HashMap<ProtectedInformationIdentifier, Result<AccessProtectedInformationApiResponse, Error>>;
+
+/// Trait to access many protected information items at once.
+///
+/// Implemented for every [AccessProtectedInformationApi].
+#[async_trait]
+pub trait BatchAccessProtectedInformationApi: Send + Sync {
+    /// Returns the protected information items, fetching at most `max_concurrency` of them
+    /// concurrently.
+    ///
+    /// Items are accessed independently, so a missing item doesn't fail the whole batch. Duplicate
+    /// identifiers are accessed only once.
+    ///
+    /// When using [SenhaseguraClient](crate::SenhaseguraClient), all requests share the same
+    /// access token.
+    async fn access_protected_information_batch(
+        &self,
+        ids: Vec<ProtectedInformationIdentifier>,
+        max_concurrency: usize,
+    ) -> BatchAccessProtectedInformationResult;
+}
+
+#[async_trait]
+impl<T> BatchAccessProtectedInformationApi for T
+where
+    T: AccessProtectedInformationApi,
+{
+    #[tracing::instrument(level = "info", skip(self), fields(n_ids = ids.len()))]
+    async fn access_protected_information_batch(
+        &self,
+        mut ids: Vec<ProtectedInformationIdentifier>,
+        max_concurrency: usize,
+    ) -> BatchAccessProtectedInformationResult {
+        {
+            let mut seen = HashSet::new();
+            ids.retain(|id| seen.insert(id.clone()));
+        }
+
+        futures::stream::iter(ids)
+            .map(|id| async move {
+                let result = self.access_protected_information(id.clone()).await;
+
+                (id, result)
+            })
+            .buffer_unordered(max_concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl SenhaseguraClient {
+    /// Returns the protected information items, fetching at most `max_concurrency` of them
+    /// concurrently.
+    pub fn access_protected_information_batch_sync(
+        &self,
+        ids: Vec<ProtectedInformationIdentifier>,
+        max_concurrency: usize,
+    ) -> Result<BatchAccessProtectedInformationResult, Error> {
+        Ok(self.async_runtime()?.block_on(
+            <Self as BatchAccessProtectedInformationApi>::access_protected_information_batch(
+                self,
+                ids,
+                max_concurrency,
+            ),
+        ))
+    }
+}
+
+#[cfg(feature = "napi")]
+mod senhasegura_js {
+    use napi_derive::napi;
+
+    use crate::SenhaseguraClient;
+
+    use super::*;
+
+    /// Batch access protected information result item.
+    #[napi(object)]
+    pub struct BatchAccessProtectedInformationItem {
+        /// Protected information item's ID or tag.
+        #[napi(ts_type = "number | string")]
+        pub id: ProtectedInformationIdentifier,
+
+        /// Access protected information response, if successful.
+        pub response: Option<AccessProtectedInformationApiResponse>,
+
+        /// Error, if unsuccessful.
+        pub error: Option<Error>,
+    }
+
+    #[napi]
+    impl SenhaseguraClient {
+        /// Returns the protected information items, fetching at most `maxConcurrency` of them
+        /// concurrently (defaults to 8).
+        #[napi(js_name = accessProtectedInformationBatch)]
+        pub async fn js_access_protected_information_batch(
+            &self,
+            ids: Vec<napi::Either<i32, String>>,
+            max_concurrency: Option<u32>,
+        ) -> napi::Result<Vec<BatchAccessProtectedInformationItem>> {
+            let ids = ids.into_iter().map(Into::into).collect();
+            let max_concurrency = max_concurrency.unwrap_or(8) as usize;
+
+            let items =
+                <Self as BatchAccessProtectedInformationApi>::access_protected_information_batch(
+                    self,
+                    ids,
+                    max_concurrency,
+                )
+                .await
+                .into_iter()
+                .map(|(id, result)| {
+                    let (response, error) = match result {
+                        Ok(response) => (Some(response), None),
+                        Err(e) => (None, Some(e)),
+                    };
+
+                    BatchAccessProtectedInformationItem {
+                        id,
+                        response,
+                        error,
+                    }
+                })
+                .collect();
+
+            Ok(items)
+        }
+    }
+}
+
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::{prelude::*, types::PyDict};
+
+    use crate::SenhaseguraClient;
+
+    use super::*;
+
+    /// Converts the result into a dictionary of responses (if successful) or exceptions (if
+    /// unsuccessful), by protected information item.
+    fn into_py_dict(
+        py: Python<'_>,
+        result: BatchAccessProtectedInformationResult,
+    ) -> PyResult<Py<PyDict>> {
+        let dict = PyDict::new(py);
+
+        for (id, result) in result {
+            match result {
+                Ok(response) => dict.set_item(id, response)?,
+                Err(e) => dict.set_item(id, PyErr::from(e).into_value(py))?,
+            }
+        }
+
+        Ok(dict.unbind())
+    }
+
+    #[pymethods]
+    impl SenhaseguraClient {
+        /// Returns the protected information items, fetching at most `max_concurrency` of them
+        /// concurrently.
+        #[pyo3(
+            name = "access_protected_information_batch",
+            signature = (ids, max_concurrency = 8)
+        )]
+        fn py_access_protected_information_batch(
+            &self,
+            py: Python<'_>,
+            ids: Vec<ProtectedInformationIdentifier>,
+            max_concurrency: usize,
+        ) -> PyResult<Py<PyDict>> {
+            let result = py.allow_threads(|| {
+                self.access_protected_information_batch_sync(ids, max_concurrency)
+            })?;
+
+            into_py_dict(py, result)
+        }
+
+        /// Returns the protected information items, fetching at most `max_concurrency` of them
+        /// concurrently, asynchronously.
+        #[pyo3(
+            name = "access_protected_information_batch_async",
+            signature = (ids, max_concurrency = 8)
+        )]
+        fn py_access_protected_information_batch_async<'py>(
+            slf: &Bound<'py, Self>,
+            ids: Vec<ProtectedInformationIdentifier>,
+            max_concurrency: usize,
+        ) -> PyResult<Bound<'py, PyAny>> {
+            crate::run_in_executor(
+                slf.getattr("access_protected_information_batch")?,
+                (ids, max_concurrency),
+            )
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use async_trait::async_trait;
 use http::Method;
 
-use crate::{Error, Response, SenhaseguraClient};
+use crate::{Error, Operation, Response, SenhaseguraClient};
 
 /// Create protected information API request.
-#[derive(serde::Serialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, set_all, module = "senhasegura")
+)]
 pub struct CreateProtectedInformationApiRequest {
     /// Name assigned to the protected item.
     pub name: Option<String>,
@@ -24,9 +28,13 @@ pub struct CreateProtectedInformationApiRequest {
 }
 
 /// Create protected information API response.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct CreateProtectedInformationApiResponse {
     /// Response.
     pub response: Response,
@@ -36,9 +44,13 @@ pub struct CreateProtectedInformationApiResponse {
 }
 
 /// Create protected information result (i.e. "info") field.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct CreateProtectedInformationResult {
     /// Name assigned to the protected item.
     pub name: Option<String>,
@@ -81,13 +93,18 @@ impl CreateProtectedInformationApi for SenhaseguraClient {
         &self,
         request: CreateProtectedInformationApiRequest,
     ) -> Result<CreateProtectedInformationApiResponse, Error> {
-        self.do_api_request(Method::POST, "iso/pam/info", Some(request))
+        let operation = Operation {
+            name: "create_protected_information",
+            identifier_kind: request.identifier.as_ref().map(|_| "tag"),
+            url_template: "iso/pam/info",
+        };
+
+        self.do_api_request(operation, Method::POST, "iso/pam/info", Some(request))
             .await
     }
 }
 
 #[cfg(feature = "blocking")]
-#[cfg_attr(feature = "uniffi", uniffi::export)]
 impl SenhaseguraClient {
     /// Creates a protected information item.
     pub fn create_protected_information_sync(
@@ -120,3 +137,95 @@ mod senhasegura_js {
         }
     }
 }
+
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::prelude::*;
+
+    use super::*;
+
+    #[pymethods]
+    impl CreateProtectedInformationApiRequest {
+        /// Creates a new create protected information API request.
+        #[new]
+        #[pyo3(signature = (content, *, name = None, identifier = None, r#type = None))]
+        fn py_new(
+            content: String,
+            name: Option<String>,
+            identifier: Option<String>,
+            r#type: Option<String>,
+        ) -> Self {
+            Self {
+                name,
+                content,
+                identifier,
+                r#type,
+            }
+        }
+    }
+
+    #[pymethods]
+    impl SenhaseguraClient {
+        /// Creates a protected information item.
+        #[pyo3(name = "create_protected_information")]
+        fn py_create_protected_information(
+            &self,
+            py: Python<'_>,
+            request: CreateProtectedInformationApiRequest,
+        ) -> PyResult<CreateProtectedInformationApiResponse> {
+            py.allow_threads(|| {
+                self.async_runtime()?.block_on(
+                    <Self as CreateProtectedInformationApi>::create_protected_information(
+                        self, request,
+                    ),
+                )
+            })
+            .map_err(Into::into)
+        }
+
+        /// Creates a protected information item, asynchronously.
+        #[pyo3(name = "create_protected_information_async")]
+        fn py_create_protected_information_async<'py>(
+            slf: &Bound<'py, Self>,
+            request: CreateProtectedInformationApiRequest,
+        ) -> PyResult<Bound<'py, PyAny>> {
+            crate::run_in_executor(slf.getattr("create_protected_information")?, (request,))
+        }
+    }
+}
+
+#[cfg(feature = "uniffi")]
+mod senhasegura_uniffi {
+    use std::sync::Arc;
+
+    use crate::SenhaseguraError;
+
+    use super::*;
+
+    #[uniffi::export]
+    impl SenhaseguraClient {
+        /// Creates a protected information item.
+        pub fn create_protected_information_blocking(
+            &self,
+            request: CreateProtectedInformationApiRequest,
+        ) -> Result<CreateProtectedInformationApiResponse, SenhaseguraError> {
+            self.create_protected_information_sync(request)
+                .map_err(Into::into)
+        }
+
+        /// Creates a protected information item, asynchronously.
+        pub async fn create_protected_information_async(
+            self: Arc<Self>,
+            request: CreateProtectedInformationApiRequest,
+        ) -> Result<CreateProtectedInformationApiResponse, SenhaseguraError> {
+            crate::spawn_request(self.clone(), async move {
+                <Self as CreateProtectedInformationApi>::create_protected_information(
+                    &self, request,
+                )
+                .await
+            })
+            .await
+            .map_err(Into::into)
+        }
+    }
+}
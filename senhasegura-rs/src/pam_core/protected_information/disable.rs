@@ -1,14 +1,18 @@
 use async_trait::async_trait;
 use http::Method;
 
-use crate::{Error, Response, SenhaseguraClient};
+use crate::{Error, Operation, Response, SenhaseguraClient};
 
 use super::ProtectedInformationIdentifier;
 
 /// Disable protected information API response.
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(get_all, frozen, module = "senhasegura")
+)]
 pub struct DisableProtectedInformationApiResponse {
     /// Response.
     pub response: Response,
@@ -33,9 +37,18 @@ impl DisableProtectedInformationApi for SenhaseguraClient {
         &self,
         id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
     ) -> Result<DisableProtectedInformationApiResponse, Error> {
+        let id = id.into();
+
+        let operation = Operation {
+            name: "disable_protected_information",
+            identifier_kind: Some(id.kind()),
+            url_template: "iso/pam/info/{identifier}",
+        };
+
         self.do_api_request(
+            operation,
             Method::DELETE,
-            format!("iso/pam/info/{}", id.into()),
+            format!("iso/pam/info/{id}"),
             None::<()>,
         )
         .await
@@ -43,7 +56,6 @@ impl DisableProtectedInformationApi for SenhaseguraClient {
 }
 
 #[cfg(feature = "blocking")]
-#[cfg_attr(feature = "uniffi", uniffi::export)]
 impl SenhaseguraClient {
     /// Disables the protected information item.
     pub fn disable_protected_information_sync(
@@ -76,3 +88,73 @@ mod senhasegura_js {
         }
     }
 }
+
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::prelude::*;
+
+    use super::*;
+
+    #[pymethods]
+    impl SenhaseguraClient {
+        /// Disables the protected information item.
+        #[pyo3(name = "disable_protected_information")]
+        fn py_disable_protected_information(
+            &self,
+            py: Python<'_>,
+            id: ProtectedInformationIdentifier,
+        ) -> PyResult<DisableProtectedInformationApiResponse> {
+            py.allow_threads(|| {
+                self.async_runtime()?.block_on(
+                    <Self as DisableProtectedInformationApi>::disable_protected_information(
+                        self, id,
+                    ),
+                )
+            })
+            .map_err(Into::into)
+        }
+
+        /// Disables the protected information item, asynchronously.
+        #[pyo3(name = "disable_protected_information_async")]
+        fn py_disable_protected_information_async<'py>(
+            slf: &Bound<'py, Self>,
+            id: ProtectedInformationIdentifier,
+        ) -> PyResult<Bound<'py, PyAny>> {
+            crate::run_in_executor(slf.getattr("disable_protected_information")?, (id,))
+        }
+    }
+}
+
+#[cfg(feature = "uniffi")]
+mod senhasegura_uniffi {
+    use std::sync::Arc;
+
+    use crate::SenhaseguraError;
+
+    use super::*;
+
+    #[uniffi::export]
+    impl SenhaseguraClient {
+        /// Disables the protected information item.
+        pub fn disable_protected_information_blocking(
+            &self,
+            id: String,
+        ) -> Result<DisableProtectedInformationApiResponse, SenhaseguraError> {
+            self.disable_protected_information_sync(id)
+                .map_err(Into::into)
+        }
+
+        /// Disables the protected information item, asynchronously.
+        pub async fn disable_protected_information_async(
+            self: Arc<Self>,
+            id: String,
+        ) -> Result<DisableProtectedInformationApiResponse, SenhaseguraError> {
+            crate::spawn_request(self.clone(), async move {
+                <Self as DisableProtectedInformationApi>::disable_protected_information(&self, id)
+                    .await
+            })
+            .await
+            .map_err(Into::into)
+        }
+    }
+}
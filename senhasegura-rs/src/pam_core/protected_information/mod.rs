@@ -1,6 +1,9 @@
 mod access;
 pub use access::*;
 
+mod batch_access;
+pub use batch_access::*;
+
 mod create;
 pub use create::*;
 
@@ -8,7 +11,7 @@ mod disable;
 pub use disable::*;
 
 /// Protected information identifier.
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ProtectedInformationIdentifier {
     /// Protected information item ’s unique identification code.
     Id(i32),
@@ -17,6 +20,16 @@ pub enum ProtectedInformationIdentifier {
     Tag(String),
 }
 
+impl ProtectedInformationIdentifier {
+    /// Returns the kind of the identifier (i.e. "id" or "tag").
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Id(_) => "id",
+            Self::Tag(_) => "tag",
+        }
+    }
+}
+
 impl From<i32> for ProtectedInformationIdentifier {
     fn from(id: i32) -> Self {
         ProtectedInformationIdentifier::Id(id)
@@ -69,8 +82,21 @@ impl std::fmt::Display for ProtectedInformationIdentifier {
 }
 
 /// Protected information exception codes.
-#[derive(serde_repr::Deserialize_repr, strum::FromRepr, Debug)]
+#[derive(
+    serde_repr::Serialize_repr,
+    serde_repr::Deserialize_repr,
+    strum::FromRepr,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+)]
 #[repr(u16)]
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3::pyclass(eq, eq_int, frozen, module = "senhasegura")
+)]
 pub enum ProtectedInformationExceptionCode {
     /// Information not found.
     InformationNotFound = 1023,
@@ -147,3 +173,43 @@ mod senhasegura_js {
         }
     }
 }
+
+#[cfg(feature = "pyo3")]
+mod senhasegura_py {
+    use pyo3::{
+        exceptions::PyTypeError,
+        prelude::*,
+        types::{PyInt, PyString},
+    };
+
+    use super::*;
+
+    impl<'py> FromPyObject<'py> for ProtectedInformationIdentifier {
+        fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+            if ob.is_instance_of::<PyInt>() {
+                ob.extract().map(ProtectedInformationIdentifier::Id)
+            } else if ob.is_instance_of::<PyString>() {
+                ob.extract().map(ProtectedInformationIdentifier::Tag)
+            } else {
+                Err(PyTypeError::new_err(
+                    "protected information identifier must be an ID (int) or a tag (str)",
+                ))
+            }
+        }
+    }
+
+    impl<'py> IntoPyObject<'py> for ProtectedInformationIdentifier {
+        type Target = PyAny;
+        type Output = Bound<'py, PyAny>;
+        type Error = PyErr;
+
+        fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+            use ProtectedInformationIdentifier::*;
+
+            match self {
+                Id(v) => Ok(v.into_pyobject(py)?.into_any()),
+                Tag(v) => Ok(v.into_pyobject(py)?.into_any()),
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use futures::future::BoxFuture;
+use tokio::sync::OnceCell;
+use url::Url;
+
+use crate::{Error, SenhaseguraClient, SenhaseguraClientBuilder};
+
+type Provider<K> =
+    dyn Fn(&K) -> BoxFuture<'static, Result<SenhaseguraClientBuilder, Error>> + Send + Sync;
+
+/// Key of the HTTP clients shared by tenants, i.e. their base URL and request timeout.
+type HttpClientKey = (Url, Option<Duration>);
+
+struct PoolEntry {
+    client: Arc<OnceCell<(Arc<SenhaseguraClient>, HttpClientKey)>>,
+    last_used: Instant,
+}
+
+/// Pool of Senhasegura API clients of many tenants (e.g. business units with their own A2A
+/// application credentials and appliances).
+///
+/// Clients are lazily built, on their first use, from the builders returned by the provider, then
+/// cached by tenant key. Clients of tenants with the same base URL (and request timeout) share the
+/// underlying HTTP connection pool, which is dropped once no tenant in the pool uses it. Tenants idle
+/// for longer than the idle timeout are evicted, while clients already handed out remain usable.
+///
+/// # Example
+///
+/// ```no_run
+/// use senhasegura_rs::{
+///     AccessProtectedInformationApi, SenhaseguraClientBuilder, SenhaseguraClientPool,
+/// };
+///
+/// # tokio_test::block_on(async {
+/// let pool = SenhaseguraClientPool::builder(|tenant: &String| {
+///     let tenant = tenant.clone();
+///
+///     async move {
+///         let base_url = "https://senhasegura.acme.com".parse()?;
+///         let client_secret = std::fs::read_to_string(format!("/run/secrets/{tenant}"))?;
+///
+///         Ok(SenhaseguraClientBuilder::new(base_url, tenant, client_secret))
+///     }
+/// })
+/// .build();
+///
+/// let client = pool.get(&"payments".to_string()).await?;
+///
+/// println!("{:#?}", client.access_protected_information(28).await?);
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+pub struct SenhaseguraClientPool<K> {
+    provider: Arc<Provider<K>>,
+
+    idle_timeout: Duration,
+    request_timeout: Option<Duration>,
+
+    entries: Mutex<HashMap<K, PoolEntry>>,
+    http_clients: Mutex<HashMap<HttpClientKey, reqwest::Client>>,
+}
+
+impl<K> SenhaseguraClientPool<K>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Creates a new client pool builder, with the provider of the client builder of each tenant.
+    pub fn builder<F, Fut>(provider: F) -> SenhaseguraClientPoolBuilder<K>
+    where
+        F: Fn(&K) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<SenhaseguraClientBuilder, Error>> + Send + 'static,
+    {
+        SenhaseguraClientPoolBuilder::new(provider)
+    }
+
+    /// Returns the client of the tenant, building it if needed.
+    ///
+    /// Concurrent calls for the same tenant build its client only once. Failures aren't cached,
+    /// thus the next call retries building the client.
+    pub async fn get(&self, key: &K) -> Result<Arc<SenhaseguraClient>, Error> {
+        self.evict_idle();
+
+        let cell = {
+            let mut entries = self.entries.lock().unwrap();
+
+            let now = Instant::now();
+            let entry = entries.entry(key.clone()).or_insert_with(|| PoolEntry {
+                client: Default::default(),
+                last_used: now,
+            });
+            entry.last_used = now;
+
+            entry.client.clone()
+        };
+
+        let client = cell
+            .get_or_try_init(|| async {
+                let builder = (self.provider)(key).await?;
+
+                tracing::debug!(base_url = %builder.base_url, "building client of tenant");
+
+                let http_client_key = (
+                    builder.base_url.clone(),
+                    builder.request_timeout.or(self.request_timeout),
+                );
+                let http_client = self.http_client(&http_client_key)?;
+
+                let client = builder.http_client(http_client).build()?;
+
+                Ok::<_, Error>((Arc::new(client), http_client_key))
+            })
+            .await?;
+
+        Ok(client.0.clone())
+    }
+
+    /// Removes the client of the tenant from the pool (e.g. after its credentials were rotated).
+    pub fn remove(&self, key: &K) {
+        let mut entries = self.entries.lock().unwrap();
+
+        entries.remove(key);
+
+        self.evict_http_clients(&entries);
+    }
+
+    /// Removes the clients of tenants idle for longer than the idle timeout.
+    ///
+    /// Idle tenants are also evicted whenever a client is requested.
+    pub fn evict_idle(&self) {
+        let now = Instant::now();
+
+        let mut entries = self.entries.lock().unwrap();
+
+        entries.retain(|_, entry| now.duration_since(entry.last_used) < self.idle_timeout);
+
+        self.evict_http_clients(&entries);
+    }
+
+    /// Returns the number of tenants in the pool.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Returns whether the pool has no tenants.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of HTTP clients (i.e. connection pools) shared by the tenants in the
+    /// pool.
+    pub fn n_http_clients(&self) -> usize {
+        self.http_clients.lock().unwrap().len()
+    }
+
+    /// Returns the HTTP client shared by tenants with the same base URL and request timeout.
+    fn http_client(&self, key: &HttpClientKey) -> Result<reqwest::Client, Error> {
+        let mut http_clients = self.http_clients.lock().unwrap();
+
+        if let Some(http_client) = http_clients.get(key) {
+            return Ok(http_client.clone());
+        }
+
+        let http_client = crate::new_http_client(key.1)?;
+        http_clients.insert(key.clone(), http_client.clone());
+
+        Ok(http_client)
+    }
+
+    /// Drops the HTTP clients which no tenant in the pool uses anymore (clients already handed out
+    /// keep their own).
+    fn evict_http_clients(&self, entries: &HashMap<K, PoolEntry>) {
+        let used: HashSet<&HttpClientKey> = entries
+            .values()
+            .filter_map(|entry| entry.client.get())
+            .map(|(_, key)| key)
+            .collect();
+
+        self.http_clients
+            .lock()
+            .unwrap()
+            .retain(|key, _| used.contains(key));
+    }
+}
+
+/// Senhasegura API client pool builder.
+pub struct SenhaseguraClientPoolBuilder<K> {
+    provider: Arc<Provider<K>>,
+
+    idle_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+}
+
+impl<K> SenhaseguraClientPoolBuilder<K> {
+    /// Creates a new client pool builder, with the provider of the client builder of each tenant.
+    pub fn new<F, Fut>(provider: F) -> Self
+    where
+        F: Fn(&K) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<SenhaseguraClientBuilder, Error>> + Send + 'static,
+    {
+        Self {
+            provider: Arc::new(move |key: &K| -> BoxFuture<'static, _> { Box::pin(provider(key)) }),
+
+            idle_timeout: None,
+            request_timeout: None,
+        }
+    }
+
+    /// Sets for how long tenants may be idle before being evicted.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Sets the request timeout of tenants whose builders don't set one.
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = Some(request_timeout);
+        self
+    }
+
+    /// Builds the client pool.
+    pub fn build(self) -> SenhaseguraClientPool<K> {
+        SenhaseguraClientPool {
+            provider: self.provider,
+
+            idle_timeout: self.idle_timeout.unwrap_or(Duration::from_secs(600)),
+            request_timeout: self.request_timeout,
+
+            entries: Default::default(),
+            http_clients: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::{io::Write, path::Path};
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    AccessProtectedInformationApi, AccessProtectedInformationApiResponse,
+    CreateProtectedInformationApi, CreateProtectedInformationApiRequest,
+    CreateProtectedInformationApiResponse, DisableProtectedInformationApi,
+    DisableProtectedInformationApiResponse, Error, ProtectedInformationApi,
+    ProtectedInformationIdentifier,
+};
+
+/// Magic bytes at the start of every snapshot file.
+const MAGIC: &[u8; 4] = b"SSNP";
+
+/// Current version of the snapshot file format.
+const VERSION: u8 = 1;
+
+const NONCE_LEN: usize = 12;
+
+/// Snapshot encryption key (AES-256-GCM).
+#[derive(Clone)]
+pub struct SnapshotKey(Key<Aes256Gcm>);
+
+impl SnapshotKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        Self(Aes256Gcm::generate_key(OsRng))
+    }
+
+    /// Returns the raw bytes of the key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.into()
+    }
+}
+
+impl From<[u8; 32]> for SnapshotKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes.into())
+    }
+}
+
+impl TryFrom<&[u8]> for SnapshotKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow!("snapshot key must be 32 bytes long"))?;
+
+        Ok(bytes.into())
+    }
+}
+
+impl std::fmt::Debug for SnapshotKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SnapshotKey(..)")
+    }
+}
+
+/// Offline snapshot of protected information items.
+///
+/// Snapshots are exported from a live client and stored in a local file, encrypted with a key
+/// supplied by the caller. They may then be served by [SnapshotClient] when the API is
+/// unreachable (e.g. during disaster recovery).
+///
+/// # Example
+///
+/// ```no_run
+/// use senhasegura_rs::{SenhaseguraClient, Snapshot, SnapshotKey};
+///
+/// # tokio_test::block_on(async {
+/// let base_url = "https://senhasegura.acme.com".parse()?;
+///
+/// let client = SenhaseguraClient::builder(base_url, "client_id", "client_secret").build()?;
+///
+/// let key = SnapshotKey::from([0u8; 32]);
+///
+/// Snapshot::export(&client, [28, 29]).await?.write("secrets.snapshot", &key)?;
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct Snapshot {
+    exported_at: DateTime<Utc>,
+    items: Vec<AccessProtectedInformationApiResponse>,
+}
+
+impl Snapshot {
+    /// Exports the protected information items from the client.
+    ///
+    /// Fails if any of the items can't be accessed, so that snapshots are never partial.
+    #[tracing::instrument(level = "info", skip_all, err)]
+    pub async fn export<T, I>(client: &T, ids: impl IntoIterator<Item = I>) -> Result<Self, Error>
+    where
+        T: AccessProtectedInformationApi,
+        I: Into<ProtectedInformationIdentifier>,
+    {
+        let mut items = Vec::new();
+
+        for id in ids {
+            items.push(client.access_protected_information(id.into()).await?);
+        }
+
+        Ok(Self {
+            exported_at: Utc::now(),
+            items,
+        })
+    }
+
+    /// Returns when the snapshot was exported.
+    pub fn exported_at(&self) -> DateTime<Utc> {
+        self.exported_at
+    }
+
+    /// Returns the protected information items in the snapshot.
+    pub fn items(&self) -> &[AccessProtectedInformationApiResponse] {
+        &self.items
+    }
+
+    /// Returns the protected information item, by its ID or tag.
+    pub fn get(
+        &self,
+        id: &ProtectedInformationIdentifier,
+    ) -> Option<&AccessProtectedInformationApiResponse> {
+        self.items.iter().find(|item| {
+            *id == item.info.id || item.info.tag.as_ref().is_some_and(|tag| *id == *tag)
+        })
+    }
+
+    /// Encrypts the snapshot with the key.
+    pub fn encrypt(&self, key: &SnapshotKey) -> Result<Vec<u8>, Error> {
+        let plaintext = serde_json::to_vec(self).map_err(anyhow::Error::from)?;
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = Aes256Gcm::new(&key.0)
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|e| anyhow!("failed to encrypt snapshot: {e}"))?;
+
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 1 + NONCE_LEN + ciphertext.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&nonce);
+        bytes.extend_from_slice(&ciphertext);
+
+        Ok(bytes)
+    }
+
+    /// Decrypts the snapshot with the key.
+    pub fn decrypt(bytes: &[u8], key: &SnapshotKey) -> Result<Self, Error> {
+        let Some(bytes) = bytes.strip_prefix(MAGIC) else {
+            return Err(anyhow!("not a snapshot file").into());
+        };
+
+        let (version, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| anyhow!("truncated snapshot file"))?;
+
+        if *version != VERSION {
+            return Err(anyhow!("unsupported snapshot version: {version}").into());
+        }
+
+        if bytes.len() < NONCE_LEN {
+            return Err(anyhow!("truncated snapshot file").into());
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+
+        let plaintext = Aes256Gcm::new(&key.0)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("failed to decrypt snapshot (wrong key?)"))?;
+
+        let snapshot = serde_json::from_slice(&plaintext).map_err(anyhow::Error::from)?;
+
+        Ok(snapshot)
+    }
+
+    /// Atomically writes the snapshot to a file, encrypted with the key.
+    ///
+    /// On Unix, the file is only readable / writable by its owner, even if it already existed.
+    pub fn write(&self, path: impl AsRef<Path>, key: &SnapshotKey) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        let bytes = self.encrypt(key)?;
+
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            file.as_file()
+                .set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+
+        file.write_all(&bytes)?;
+        file.as_file().sync_all()?;
+
+        file.persist(path).map_err(|e| e.error)?;
+
+        Ok(())
+    }
+
+    /// Reads a snapshot from a file, decrypting it with the key.
+    pub fn read(path: impl AsRef<Path>, key: &SnapshotKey) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+
+        Self::decrypt(&bytes, key)
+    }
+}
+
+tokio::task_local! {
+    static SERVED_FROM_SNAPSHOT: std::cell::Cell<bool>;
+}
+
+/// Runs the future, returning its output along with whether any protected information accessed
+/// within it was served from a snapshot by [SnapshotClient].
+///
+/// Accesses spawned onto other tasks don't count.
+///
+/// # Example
+///
+/// ```no_run
+/// use senhasegura_rs::{
+///     with_served_from_snapshot, AccessProtectedInformationApi, SenhaseguraClient, SnapshotClient,
+/// };
+///
+/// # async fn example(client: SnapshotClient<SenhaseguraClient>) -> Result<(), anyhow::Error> {
+/// let (response, from_snapshot) =
+///     with_served_from_snapshot(client.access_protected_information(28)).await;
+///
+/// println!("{} served from snapshot: {from_snapshot}", response?.info.id);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_served_from_snapshot<F>(future: F) -> (F::Output, bool)
+where
+    F: std::future::Future,
+{
+    SERVED_FROM_SNAPSHOT
+        .scope(Default::default(), async {
+            let output = future.await;
+
+            (output, SERVED_FROM_SNAPSHOT.with(std::cell::Cell::get))
+        })
+        .await
+}
+
+/// Client which falls back to an offline snapshot.
+///
+/// Protected information is accessed from the inner client, falling back to the snapshot when the
+/// API is unavailable (see [Error::is_unavailable]). Whether responses were served from the
+/// snapshot is reported by [with_served_from_snapshot].
+///
+/// Creating and disabling protected information always goes through the inner client.
+#[derive(Debug)]
+pub struct SnapshotClient<T> {
+    inner: T,
+    snapshot: Snapshot,
+}
+
+impl<T> SnapshotClient<T> {
+    /// Creates a new client which falls back to the snapshot.
+    pub fn new(inner: T, snapshot: Snapshot) -> Self {
+        Self { inner, snapshot }
+    }
+
+    /// Returns the inner client.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the snapshot.
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+}
+
+#[async_trait]
+impl<T> AccessProtectedInformationApi for SnapshotClient<T>
+where
+    T: ProtectedInformationApi,
+{
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    async fn access_protected_information(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+    ) -> Result<AccessProtectedInformationApiResponse, Error> {
+        let id = id.into();
+
+        match self.inner.access_protected_information(id.clone()).await {
+            Err(e) if e.is_unavailable() => {
+                let Some(item) = self.snapshot.get(&id) else {
+                    return Err(e);
+                };
+
+                tracing::warn!(
+                    error = %e,
+                    exported_at = %self.snapshot.exported_at,
+                    "serving protected information from snapshot"
+                );
+
+                let _ = SERVED_FROM_SNAPSHOT.try_with(|served| served.set(true));
+
+                Ok(item.clone())
+            }
+            result => result,
+        }
+    }
+}
+
+#[async_trait]
+impl<T> CreateProtectedInformationApi for SnapshotClient<T>
+where
+    T: ProtectedInformationApi,
+{
+    async fn create_protected_information(
+        &self,
+        request: CreateProtectedInformationApiRequest,
+    ) -> Result<CreateProtectedInformationApiResponse, Error> {
+        self.inner.create_protected_information(request).await
+    }
+}
+
+#[async_trait]
+impl<T> DisableProtectedInformationApi for SnapshotClient<T>
+where
+    T: ProtectedInformationApi,
+{
+    async fn disable_protected_information(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+    ) -> Result<DisableProtectedInformationApiResponse, Error> {
+        self.inner.disable_protected_information(id).await
+    }
+}
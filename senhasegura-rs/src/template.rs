@@ -0,0 +1,399 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    AccessProtectedInformationApi, AccessProtectedInformationResult,
+    BatchAccessProtectedInformationApi, Error, ProtectedInformationIdentifier, SecretWatcher,
+};
+
+/// Maximum number of protected information items accessed concurrently.
+const MAX_CONCURRENCY: usize = 8;
+
+/// Field of a protected information item, selected by a placeholder.
+///
+/// The name of an item can't be selected, since the access protected information API doesn't
+/// return it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemplateField {
+    /// Protected information item's ID.
+    Id,
+
+    /// Information identifier.
+    Tag,
+
+    /// Information type.
+    Type,
+
+    /// Information content.
+    Content,
+}
+
+impl TemplateField {
+    fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "id" => Ok(Self::Id),
+            "tag" => Ok(Self::Tag),
+            "type" => Ok(Self::Type),
+            "content" => Ok(Self::Content),
+            "name" => {
+                Err("field name isn't returned by the access protected information API".into())
+            }
+            _ => Err(format!(
+                "unknown field {s:?}, expected one of id, tag, type or content"
+            )),
+        }
+    }
+
+    fn value(self, info: &AccessProtectedInformationResult) -> String {
+        match self {
+            Self::Id => info.id.to_string(),
+            Self::Tag => info.tag.clone().unwrap_or_default(),
+            Self::Type => info.r#type.clone().unwrap_or_default(),
+            Self::Content => info.content.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Segment {
+    Text(String),
+    Placeholder {
+        id: ProtectedInformationIdentifier,
+        field: TemplateField,
+    },
+}
+
+/// Template of a file which embeds protected information.
+///
+/// Placeholders reference protected information items by ID or tag, optionally selecting one of
+/// their fields (see [TemplateField]), which defaults to the content:
+///
+/// ```text
+/// password = {{ info:db-prod }}
+/// type = {{ info:28 type }}
+/// ```
+///
+/// Anything else between double braces is left as is, so that templates may contain other
+/// templating syntax.
+///
+/// Note that the name of an item (e.g. `{{ info:28 name }}`) isn't supported, since the access
+/// protected information API doesn't return it; such placeholders are rejected when parsing.
+#[derive(Clone, Debug)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Parses a template.
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut segments = Vec::new();
+        let mut rest = source;
+
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start + 2..].find("}}") else {
+                break;
+            };
+
+            let inner = rest[start + 2..start + 2 + len].trim();
+            let end = start + 2 + len + 2;
+
+            if let Some(spec) = inner.strip_prefix("info:") {
+                let offset = source.len() - rest.len() + start;
+                let line = source[..offset].matches('\n').count() + 1;
+
+                let placeholder = parse_placeholder(spec)
+                    .map_err(|e| anyhow!("invalid placeholder at line {line}: {e}"))?;
+
+                segments.push(Segment::Text(rest[..start].to_string()));
+                segments.push(placeholder);
+            } else {
+                segments.push(Segment::Text(rest[..end].to_string()));
+            }
+
+            rest = &rest[end..];
+        }
+
+        segments.push(Segment::Text(rest.to_string()));
+        segments.retain(|segment| !matches!(segment, Segment::Text(text) if text.is_empty()));
+
+        Ok(Self { segments })
+    }
+
+    /// Reads and parses a template file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Returns the protected information items referenced by the template, without duplicates.
+    pub fn identifiers(&self) -> Vec<ProtectedInformationIdentifier> {
+        let mut ids = Vec::new();
+
+        for segment in &self.segments {
+            if let Segment::Placeholder { id, .. } = segment {
+                if !ids.contains(id) {
+                    ids.push(id.clone());
+                }
+            }
+        }
+
+        ids
+    }
+
+    /// Renders the template, accessing the referenced items through the client.
+    pub async fn render<T>(&self, client: &T) -> Result<String, Error>
+    where
+        T: AccessProtectedInformationApi,
+    {
+        let results = client
+            .access_protected_information_batch(self.identifiers(), MAX_CONCURRENCY)
+            .await;
+
+        let mut values = HashMap::with_capacity(results.len());
+
+        for (id, result) in results {
+            values.insert(id, result?.info);
+        }
+
+        self.render_with(&values)
+    }
+
+    /// Renders the template with the given protected information items.
+    pub fn render_with(
+        &self,
+        values: &HashMap<ProtectedInformationIdentifier, AccessProtectedInformationResult>,
+    ) -> Result<String, Error> {
+        let mut rendered = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => rendered.push_str(text),
+                Segment::Placeholder { id, field } => {
+                    let info = values
+                        .get(id)
+                        .ok_or_else(|| anyhow!("missing protected information {id}"))?;
+
+                    rendered.push_str(&field.value(info));
+                }
+            }
+        }
+
+        Ok(rendered)
+    }
+}
+
+fn parse_placeholder(spec: &str) -> Result<Segment, String> {
+    let mut parts = spec.split_whitespace();
+
+    let id = match parts.next() {
+        Some(id) => match id.parse::<i32>() {
+            Ok(id) => id.into(),
+            Err(_) => id.into(),
+        },
+        None => return Err("missing ID or tag".into()),
+    };
+
+    let field = match parts.next() {
+        Some(field) => TemplateField::parse(field)?,
+        None => TemplateField::Content,
+    };
+
+    if let Some(part) = parts.next() {
+        return Err(format!("unexpected {part:?}"));
+    }
+
+    Ok(Segment::Placeholder { id, field })
+}
+
+/// Renderer of a template into a file.
+///
+/// The file is written atomically (i.e. through a temporary file in the same directory, which is
+/// then renamed) and, on Unix, with restrictive permissions (`0600` by default). It's only
+/// rewritten, and the reload command only executed, when the rendered content changes.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{sync::Arc, time::Duration};
+///
+/// use senhasegura_rs::{SenhaseguraClient, Template, TemplateRenderer};
+///
+/// # tokio_test::block_on(async {
+/// let base_url = "https://senhasegura.acme.com".parse()?;
+///
+/// let client = SenhaseguraClient::builder(base_url, "client_id", "client_secret").build()?;
+///
+/// TemplateRenderer::new(Template::read("pgbouncer.ini.tmpl")?, "pgbouncer.ini")
+///     .reload_command(["systemctl", "reload", "pgbouncer"])
+///     .watch(Arc::new(client), Duration::from_secs(60))
+///     .await?;
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct TemplateRenderer {
+    template: Template,
+    destination: PathBuf,
+
+    #[cfg_attr(not(unix), allow(dead_code))]
+    mode: u32,
+    reload_command: Option<Vec<OsString>>,
+}
+
+impl TemplateRenderer {
+    /// Creates a new renderer of the template into the destination file.
+    pub fn new(template: Template, destination: impl Into<PathBuf>) -> Self {
+        Self {
+            template,
+            destination: destination.into(),
+
+            mode: 0o600,
+            reload_command: None,
+        }
+    }
+
+    /// Sets the permissions of the destination file (Unix only).
+    pub fn mode(mut self, mode: u32) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the command (i.e. program and arguments) executed after the file is re-rendered.
+    pub fn reload_command(
+        mut self,
+        command: impl IntoIterator<Item = impl Into<OsString>>,
+    ) -> Self {
+        self.reload_command = Some(command.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Renders the template into the file, returning whether its content changed.
+    #[tracing::instrument(level = "info", skip_all, fields(destination = %self.destination.display()), err)]
+    pub async fn render<T>(&self, client: &T) -> Result<bool, Error>
+    where
+        T: AccessProtectedInformationApi,
+    {
+        let rendered = self.template.render(client).await?;
+
+        self.write(&rendered)
+    }
+
+    /// Renders the template into the file, then re-renders it whenever the referenced items
+    /// change (polling them on the interval), executing the reload command.
+    ///
+    /// Only returns on failure. Must be called within a Tokio runtime.
+    pub async fn watch<T>(&self, client: Arc<T>, interval: Duration) -> Result<(), Error>
+    where
+        T: AccessProtectedInformationApi + 'static,
+    {
+        let ids = self.template.identifiers();
+
+        let mut builder = SecretWatcher::builder(client).interval(interval);
+        for id in &ids {
+            builder = builder.watch(id.clone());
+        }
+
+        let watcher = builder.start().await?;
+        let mut changes = watcher.changes();
+
+        // The watcher already accessed every item, so the first render uses its values.
+        self.render_watched(&watcher, &ids).await?;
+
+        loop {
+            match changes.recv().await {
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => return Err(anyhow!("watcher stopped").into()),
+            }
+
+            // Many items might change at once, which should trigger a single render.
+            while changes.try_recv().is_ok() {}
+
+            self.render_watched(&watcher, &ids).await?;
+        }
+    }
+
+    async fn render_watched(
+        &self,
+        watcher: &SecretWatcher,
+        ids: &[ProtectedInformationIdentifier],
+    ) -> Result<(), Error> {
+        let values = ids
+            .iter()
+            .filter_map(|id| {
+                let receiver = watcher.subscribe(id)?;
+                let info = receiver.borrow().info.clone();
+
+                Some((id.clone(), info))
+            })
+            .collect();
+
+        let rendered = self.template.render_with(&values)?;
+
+        if self.write(&rendered)? {
+            tracing::info!(destination = %self.destination.display(), "template rendered");
+
+            self.reload().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Atomically writes the file, if its content changed.
+    fn write(&self, rendered: &str) -> Result<bool, Error> {
+        if std::fs::read(&self.destination).is_ok_and(|current| current == rendered.as_bytes()) {
+            return Ok(false);
+        }
+
+        let dir = match self.destination.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            file.as_file()
+                .set_permissions(std::fs::Permissions::from_mode(self.mode))?;
+        }
+
+        file.write_all(rendered.as_bytes())?;
+        file.as_file().sync_all()?;
+
+        file.persist(&self.destination).map_err(|e| e.error)?;
+
+        Ok(true)
+    }
+
+    async fn reload(&self) -> Result<(), Error> {
+        let Some((program, args)) = self
+            .reload_command
+            .as_ref()
+            .and_then(|command| command.split_first())
+        else {
+            return Ok(());
+        };
+
+        let status = tokio::process::Command::new(program)
+            .args(args)
+            .status()
+            .await?;
+
+        if !status.success() {
+            tracing::warn!(%status, "reload command failed");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,403 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    AccessProtectedInformationApi, AccessProtectedInformationApiResponse,
+    AccessProtectedInformationResult, ApiError, CreateProtectedInformationApi,
+    CreateProtectedInformationApiRequest, CreateProtectedInformationApiResponse,
+    CreateProtectedInformationResult, DisableProtectedInformationApi,
+    DisableProtectedInformationApiResponse, Error, Exception, ExceptionCode, PAMCoreExceptionCode,
+    ProtectedInformationExceptionCode, ProtectedInformationIdentifier, Response,
+};
+
+/// Protected information item stored by [FakeSenhasegura].
+///
+/// Items are active by default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FakeProtectedInformation {
+    /// Name assigned to the protected item.
+    pub name: Option<String>,
+
+    /// Information you wish to protect.
+    pub content: String,
+
+    /// Unique string to identify the protected item.
+    pub tag: Option<String>,
+
+    /// Information type.
+    pub r#type: Option<String>,
+
+    /// Flag to indicate whether the item is active (i.e. not disabled).
+    pub active: bool,
+}
+
+impl Default for FakeProtectedInformation {
+    fn default() -> Self {
+        Self {
+            name: None,
+            content: String::new(),
+            tag: None,
+            r#type: None,
+            active: true,
+        }
+    }
+}
+
+/// In-memory fake of the Senhasegura API.
+///
+/// Implements the API traits with semantics similar to the real API:
+///
+/// - Created items are assigned auto-incremented IDs, starting at 1;
+/// - Tags are unique, thus creating an item with an existing tag updates it;
+/// - Accessing / disabling missing items fails with
+///   [InformationNotFound](ProtectedInformationExceptionCode::InformationNotFound);
+/// - Accessing / disabling disabled items fails with
+///   [InactiveInformation](ProtectedInformationExceptionCode::InactiveInformation);
+/// - Creating items without content fails with
+///   [MissingContentParameter](ProtectedInformationExceptionCode::MissingContentParameter).
+///
+/// Faults may be injected (see [set_latency](Self::set_latency), [fail_next](Self::fail_next) and
+/// [expire_token](Self::expire_token)) to test retry and caching logic.
+///
+/// Clones share the same state.
+///
+/// # Example
+///
+/// ```
+/// use senhasegura_rs::{
+///     testing::FakeSenhasegura, AccessProtectedInformationApi, CreateProtectedInformationApi,
+///     CreateProtectedInformationApiRequest,
+/// };
+///
+/// # tokio_test::block_on(async {
+/// let fake = FakeSenhasegura::new();
+///
+/// fake.create_protected_information(CreateProtectedInformationApiRequest {
+///     name: None,
+///     content: "password".to_string(),
+///     identifier: Some("db-prod".to_string()),
+///     r#type: None,
+/// })
+/// .await?;
+///
+/// let response = fake.access_protected_information("db-prod").await?;
+/// assert_eq!(response.info.id, 1);
+/// assert_eq!(response.info.content, "password");
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FakeSenhasegura {
+    state: Arc<Mutex<FakeState>>,
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    last_id: i32,
+    items: BTreeMap<i32, FakeProtectedInformation>,
+    tags: HashMap<String, i32>,
+
+    n_requests: u64,
+
+    latency: Duration,
+    n_transient_failures: u32,
+    token_expired: bool,
+}
+
+impl FakeSenhasegura {
+    /// Creates a new, empty, fake.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a protected information item, returning its ID.
+    ///
+    /// If the item has a tag which already exists, the existing item is replaced instead.
+    pub fn insert(&self, item: FakeProtectedInformation) -> i32 {
+        self.state.lock().unwrap().upsert(item)
+    }
+
+    /// Inserts a protected information item with the given ID, replacing any existing item with
+    /// the same ID or tag.
+    ///
+    /// Items created afterwards are assigned IDs greater than every inserted one.
+    pub fn insert_with_id(&self, id: i32, item: FakeProtectedInformation) {
+        self.state.lock().unwrap().insert_with_id(id, item)
+    }
+
+    /// Returns the protected information item, by its ID or tag, whether it's active or not.
+    pub fn get(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier>,
+    ) -> Option<(i32, FakeProtectedInformation)> {
+        let state = self.state.lock().unwrap();
+
+        state
+            .find(&id.into())
+            .map(|id| (id, state.items[&id].clone()))
+    }
+
+    /// Returns all protected information items, by ID.
+    pub fn items(&self) -> BTreeMap<i32, FakeProtectedInformation> {
+        self.state.lock().unwrap().items.clone()
+    }
+
+    /// Returns the number of requests handled by the fake, including failed ones.
+    pub fn n_requests(&self) -> u64 {
+        self.state.lock().unwrap().n_requests
+    }
+
+    /// Sets the latency added to every request.
+    pub fn set_latency(&self, latency: Duration) {
+        self.state.lock().unwrap().latency = latency;
+    }
+
+    /// Fails the next `n` requests with a transient "503 Service Unavailable" error.
+    pub fn fail_next(&self, n: u32) {
+        self.state.lock().unwrap().n_transient_failures = n;
+    }
+
+    /// Fails the next request with a "401 Unauthorized" error, as if the access token expired.
+    pub fn expire_token(&self) {
+        self.state.lock().unwrap().token_expired = true;
+    }
+
+    /// Applies the injected faults to a new request.
+    async fn handle_request(&self) -> Result<(), Error> {
+        let (latency, fault) = {
+            let mut state = self.state.lock().unwrap();
+
+            state.n_requests += 1;
+
+            let fault = if std::mem::take(&mut state.token_expired) {
+                Some(api_error(
+                    http::StatusCode::UNAUTHORIZED,
+                    "Invalid token",
+                    None,
+                ))
+            } else if state.n_transient_failures > 0 {
+                state.n_transient_failures -= 1;
+
+                Some(api_error(
+                    http::StatusCode::SERVICE_UNAVAILABLE,
+                    "Service unavailable",
+                    None,
+                ))
+            } else {
+                None
+            };
+
+            (state.latency, fault)
+        };
+
+        if !latency.is_zero() {
+            tokio::time::sleep(latency).await;
+        }
+
+        match fault {
+            Some(api_error) => Err(Error::Api(api_error)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FakeState {
+    fn find(&self, id: &ProtectedInformationIdentifier) -> Option<i32> {
+        match id {
+            ProtectedInformationIdentifier::Id(id) => self.items.contains_key(id).then_some(*id),
+            // Like the API's routes, numeric tags refer to IDs if there's no such tag.
+            ProtectedInformationIdentifier::Tag(tag) => self.tags.get(tag).copied().or_else(|| {
+                tag.parse()
+                    .ok()
+                    .and_then(|id| self.find(&ProtectedInformationIdentifier::Id(id)))
+            }),
+        }
+    }
+
+    fn find_active(
+        &mut self,
+        id: &ProtectedInformationIdentifier,
+    ) -> Result<(i32, &mut FakeProtectedInformation), ApiError> {
+        use ProtectedInformationExceptionCode::*;
+
+        let Some(id) = self.find(id) else {
+            return Err(exception(InformationNotFound, "Information not found"));
+        };
+
+        let item = self.items.get_mut(&id).unwrap();
+
+        if !item.active {
+            return Err(exception(InactiveInformation, "Inactive information"));
+        }
+
+        Ok((id, item))
+    }
+
+    fn insert_with_id(&mut self, id: i32, item: FakeProtectedInformation) {
+        if let Some(previous) = self.items.remove(&id).and_then(|item| item.tag) {
+            self.tags.remove(&previous);
+        }
+
+        if let Some(tag) = &item.tag {
+            if let Some(previous) = self.tags.insert(tag.clone(), id) {
+                self.items.remove(&previous);
+            }
+        }
+
+        self.last_id = self.last_id.max(id);
+        self.items.insert(id, item);
+    }
+
+    fn upsert(&mut self, item: FakeProtectedInformation) -> i32 {
+        if let Some(&id) = item.tag.as_ref().and_then(|tag| self.tags.get(tag)) {
+            self.items.insert(id, item);
+
+            return id;
+        }
+
+        self.last_id += 1;
+
+        let id = self.last_id;
+
+        if let Some(tag) = &item.tag {
+            self.tags.insert(tag.clone(), id);
+        }
+
+        self.items.insert(id, item);
+
+        id
+    }
+}
+
+fn response(status: http::StatusCode, message: impl ToString) -> Response {
+    Response {
+        status: status.into(),
+        message: message.to_string(),
+        error: !status.is_success(),
+        error_code: 0,
+    }
+}
+
+fn api_error(
+    status: http::StatusCode,
+    message: impl ToString,
+    exception: Option<Exception>,
+) -> ApiError {
+    let mut response = response(status, message);
+
+    if let Some(exception) = &exception {
+        response.error_code = u16::from(exception.code.clone()) as i32;
+    }
+
+    ApiError {
+        response,
+        exception,
+    }
+}
+
+fn exception(code: ProtectedInformationExceptionCode, message: &str) -> ApiError {
+    let message = format!("{}: {message}", code as u16);
+
+    api_error(
+        http::StatusCode::BAD_REQUEST,
+        &message,
+        Some(Exception {
+            code: ExceptionCode::PAMCore(PAMCoreExceptionCode::ProtectedInformation(code)),
+            message: message.clone(),
+            detail: None,
+        }),
+    )
+}
+
+#[async_trait]
+impl AccessProtectedInformationApi for FakeSenhasegura {
+    async fn access_protected_information(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+    ) -> Result<AccessProtectedInformationApiResponse, Error> {
+        self.handle_request().await?;
+
+        let mut state = self.state.lock().unwrap();
+
+        let (id, item) = state.find_active(&id.into())?;
+
+        Ok(AccessProtectedInformationApiResponse {
+            response: response(http::StatusCode::OK, format!("Information {id}")),
+            info: AccessProtectedInformationResult {
+                id,
+                tag: item.tag.clone(),
+                r#type: item.r#type.clone(),
+                content: item.content.clone(),
+            },
+        })
+    }
+}
+
+#[async_trait]
+impl CreateProtectedInformationApi for FakeSenhasegura {
+    async fn create_protected_information(
+        &self,
+        request: CreateProtectedInformationApiRequest,
+    ) -> Result<CreateProtectedInformationApiResponse, Error> {
+        self.handle_request().await?;
+
+        if request.content.is_empty() {
+            return Err(Error::Api(exception(
+                ProtectedInformationExceptionCode::MissingContentParameter,
+                "The information content was not informed",
+            )));
+        }
+
+        let item = FakeProtectedInformation {
+            name: request.name,
+            content: request.content,
+            tag: request.identifier,
+            r#type: request.r#type,
+            active: true,
+        };
+
+        self.state.lock().unwrap().upsert(item.clone());
+
+        Ok(CreateProtectedInformationApiResponse {
+            response: response(
+                http::StatusCode::CREATED,
+                "Information successfully registered!",
+            ),
+            info: CreateProtectedInformationResult {
+                name: item.name,
+                r#type: item.r#type,
+                service: None,
+                url: None,
+                content: item.content,
+                users_allowed: None,
+                identifier: item.tag,
+            },
+        })
+    }
+}
+
+#[async_trait]
+impl DisableProtectedInformationApi for FakeSenhasegura {
+    async fn disable_protected_information(
+        &self,
+        id: impl Into<ProtectedInformationIdentifier> + std::fmt::Debug + Send,
+    ) -> Result<DisableProtectedInformationApiResponse, Error> {
+        self.handle_request().await?;
+
+        let mut state = self.state.lock().unwrap();
+
+        let (_, item) = state.find_active(&id.into())?;
+
+        item.active = false;
+
+        Ok(DisableProtectedInformationApiResponse {
+            response: response(http::StatusCode::OK, "Information successfully disabled"),
+        })
+    }
+}
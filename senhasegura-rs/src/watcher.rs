@@ -0,0 +1,224 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use tokio::{
+    sync::{broadcast, watch},
+    task::JoinHandle,
+};
+
+use crate::{
+    AccessProtectedInformationApi, AccessProtectedInformationApiResponse, Error,
+    ProtectedInformationIdentifier,
+};
+
+/// Protected information change event.
+#[derive(Clone, Debug)]
+pub struct SecretChange {
+    /// Identifier of the changed item, as it was registered in the watcher.
+    pub id: ProtectedInformationIdentifier,
+
+    /// Current protected information item.
+    pub response: Arc<AccessProtectedInformationApiResponse>,
+}
+
+/// Background watcher of protected information items.
+///
+/// Polls the registered items on an interval (with jitter), comparing the hashes of their content,
+/// and publishes changes through a [watch] channel per item, as well as a [broadcast] channel of
+/// [SecretChange] events. Polling failures are logged and the last known value is kept.
+///
+/// Polling stops when the watcher is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{sync::Arc, time::Duration};
+///
+/// use senhasegura_rs::{SecretWatcher, SenhaseguraClient};
+///
+/// # tokio_test::block_on(async {
+/// let base_url = "https://senhasegura.acme.com".parse()?;
+///
+/// let client = SenhaseguraClient::builder(base_url, "client_id", "client_secret").build()?;
+///
+/// let watcher = SecretWatcher::builder(Arc::new(client))
+///     .watch(28)
+///     .interval(Duration::from_secs(60))
+///     .start()
+///     .await?;
+///
+/// let mut db_password = watcher.subscribe(&28.into()).unwrap();
+///
+/// while db_password.changed().await.is_ok() {
+///     println!("{:#?}", db_password.borrow());
+/// }
+///
+/// # Ok::<_, anyhow::Error>(())
+/// # });
+/// ```
+pub struct SecretWatcher {
+    receivers: HashMap<ProtectedInformationIdentifier, SecretReceiver>,
+    events: broadcast::Sender<SecretChange>,
+    task: JoinHandle<()>,
+}
+
+/// Receiver of the current value of a watched protected information item.
+pub type SecretReceiver = watch::Receiver<Arc<AccessProtectedInformationApiResponse>>;
+
+impl SecretWatcher {
+    /// Creates a new protected information watcher builder.
+    pub fn builder<T>(client: Arc<T>) -> SecretWatcherBuilder<T>
+    where
+        T: AccessProtectedInformationApi + 'static,
+    {
+        SecretWatcherBuilder::new(client)
+    }
+
+    /// Subscribes to changes of a single protected information item.
+    ///
+    /// Returns `None` if the item isn't being watched.
+    pub fn subscribe(&self, id: &ProtectedInformationIdentifier) -> Option<SecretReceiver> {
+        self.receivers.get(id).cloned()
+    }
+
+    /// Subscribes to changes of all protected information items.
+    pub fn changes(&self) -> broadcast::Receiver<SecretChange> {
+        self.events.subscribe()
+    }
+}
+
+impl Drop for SecretWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Protected information watcher builder.
+pub struct SecretWatcherBuilder<T> {
+    client: Arc<T>,
+    ids: Vec<ProtectedInformationIdentifier>,
+
+    interval: Option<Duration>,
+    jitter: Option<Duration>,
+}
+
+impl<T> SecretWatcherBuilder<T>
+where
+    T: AccessProtectedInformationApi + 'static,
+{
+    /// Creates a new protected information watcher builder.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            ids: Vec::new(),
+
+            interval: None,
+            jitter: None,
+        }
+    }
+
+    /// Adds a protected information item to be watched.
+    pub fn watch(mut self, id: impl Into<ProtectedInformationIdentifier>) -> Self {
+        self.ids.push(id.into());
+        self
+    }
+
+    /// Sets the polling interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets the maximum random delay added to every polling interval.
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// Fetches the current value of every watched item and starts polling them in the background.
+    ///
+    /// Must be called within a Tokio runtime.
+    pub async fn start(self) -> Result<SecretWatcher, Error> {
+        let interval = self.interval.unwrap_or(Duration::from_secs(60));
+        let jitter = self.jitter.unwrap_or(interval / 10);
+
+        let (events, _) = broadcast::channel(64);
+
+        let mut secrets = Vec::with_capacity(self.ids.len());
+        let mut receivers = HashMap::with_capacity(self.ids.len());
+
+        for id in self.ids {
+            let response = self.client.access_protected_information(id.clone()).await?;
+
+            let hash = content_hash(&response);
+            let (sender, receiver) = watch::channel(Arc::new(response));
+
+            receivers.insert(id.clone(), receiver);
+            secrets.push(WatchedSecret { id, hash, sender });
+        }
+
+        let task = tokio::spawn(poll(self.client, secrets, events.clone(), interval, jitter));
+
+        Ok(SecretWatcher {
+            receivers,
+            events,
+            task,
+        })
+    }
+}
+
+struct WatchedSecret {
+    id: ProtectedInformationIdentifier,
+    hash: [u8; 32],
+    sender: watch::Sender<Arc<AccessProtectedInformationApiResponse>>,
+}
+
+async fn poll<T>(
+    client: Arc<T>,
+    mut secrets: Vec<WatchedSecret>,
+    events: broadcast::Sender<SecretChange>,
+    interval: Duration,
+    jitter: Duration,
+) where
+    T: AccessProtectedInformationApi,
+{
+    loop {
+        let jitter = rand::thread_rng().gen_range(Duration::ZERO..=jitter);
+
+        tokio::time::sleep(interval + jitter).await;
+
+        for secret in secrets.iter_mut() {
+            let response = match client.access_protected_information(secret.id.clone()).await {
+                Ok(response) => response,
+                Err(e) => {
+                    tracing::warn!(id = %secret.id, error = %e, "failed to poll protected information");
+
+                    continue;
+                }
+            };
+
+            let hash = content_hash(&response);
+            if hash == secret.hash {
+                continue;
+            }
+
+            tracing::info!(id = %secret.id, "protected information changed");
+
+            let response = Arc::new(response);
+
+            secret.hash = hash;
+            secret.sender.send_replace(response.clone());
+
+            // There might be no subscribers, which is fine.
+            let _ = events.send(SecretChange {
+                id: secret.id.clone(),
+                response,
+            });
+        }
+    }
+}
+
+fn content_hash(response: &AccessProtectedInformationApiResponse) -> [u8; 32] {
+    Sha256::digest(response.info.content.as_bytes()).into()
+}
@@ -0,0 +1,35 @@
+// Tests the UniFFI bindings, awaiting the async methods from a coroutine.
+
+import kotlinx.coroutines.runBlocking
+import uniffi.senhasegura.*
+
+val tag = "uniffi-kotlin"
+
+val client = SenhaseguraClient.withOptions(
+    System.getenv("SENHASEGURA_MOCK_URL"),
+    "client_id",
+    "client_secret",
+    SenhaseguraClientOptions(maxNRetries = 0u),
+)
+
+runBlocking {
+    val created = client.createProtectedInformationAsync(
+        CreateProtectedInformationApiRequest(
+            name = null,
+            content = "password",
+            identifier = tag,
+            type = null,
+        ),
+    )
+    assert(created.info.identifier == tag)
+
+    val response = client.accessProtectedInformationAsync(tag)
+    assert(response.info.content == "password")
+
+    try {
+        client.accessProtectedInformationAsync("999999")
+        throw AssertionError("expected InformationNotFound")
+    } catch (e: SenhaseguraException.InformationNotFound) {
+        // Expected
+    }
+}
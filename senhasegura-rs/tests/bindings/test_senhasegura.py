@@ -0,0 +1,42 @@
+"""Tests the UniFFI bindings, awaiting the async methods from an asyncio event loop."""
+
+import asyncio
+import os
+
+from senhasegura import (
+    CreateProtectedInformationApiRequest,
+    SenhaseguraClient,
+    SenhaseguraClientOptions,
+    SenhaseguraError,
+)
+
+TAG = "uniffi-python"
+
+
+async def main():
+    client = SenhaseguraClient.with_options(
+        os.environ["SENHASEGURA_MOCK_URL"],
+        "client_id",
+        "client_secret",
+        SenhaseguraClientOptions(max_n_retries=0),
+    )
+
+    created = await client.create_protected_information_async(
+        CreateProtectedInformationApiRequest(
+            name=None, content="password", identifier=TAG, type=None
+        )
+    )
+    assert created.info.identifier == TAG
+
+    response = await client.access_protected_information_async(TAG)
+    assert response.info.content == "password"
+
+    try:
+        await client.access_protected_information_async("999999")
+    except SenhaseguraError.InformationNotFound:
+        pass
+    else:
+        raise AssertionError("expected InformationNotFound")
+
+
+asyncio.run(main())
@@ -0,0 +1,45 @@
+// Tests the UniFFI bindings, awaiting the async methods from a task.
+
+import Foundation
+import senhasegura
+
+let tag = "uniffi-swift"
+
+let client = try! SenhaseguraClient.withOptions(
+    baseUrl: ProcessInfo.processInfo.environment["SENHASEGURA_MOCK_URL"]!,
+    clientId: "client_id",
+    clientSecret: "client_secret",
+    options: SenhaseguraClientOptions(maxNRetries: 0)
+)
+
+let done = DispatchSemaphore(value: 0)
+
+Task {
+    defer { done.signal() }
+
+    do {
+        let created = try await client.createProtectedInformationAsync(
+            request: CreateProtectedInformationApiRequest(
+                name: nil,
+                content: "password",
+                identifier: tag,
+                type: nil
+            )
+        )
+        precondition(created.info.identifier == tag)
+
+        let response = try await client.accessProtectedInformationAsync(id: tag)
+        precondition(response.info.content == "password")
+
+        do {
+            _ = try await client.accessProtectedInformationAsync(id: "999999")
+            fatalError("expected InformationNotFound")
+        } catch SenhaseguraError.InformationNotFound {
+            // Expected
+        }
+    } catch {
+        fatalError("unexpected error: \(error)")
+    }
+}
+
+done.wait()
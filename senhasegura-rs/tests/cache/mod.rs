@@ -0,0 +1,362 @@
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{
+    AccessProtectedInformationApi, CacheMetrics, CachedClient, DisableProtectedInformationApi,
+    Error, SenhaseguraClient,
+};
+
+use crate::fixture::Fixture;
+
+fn inner_client(fixture: &Fixture) -> SenhaseguraClient {
+    let base_url = Url::parse(&fixture.server().uri()).unwrap();
+
+    SenhaseguraClient::builder(base_url, Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+        .disable_retries()
+        .build()
+        .unwrap()
+}
+
+fn access_response(id: i32) -> ResponseTemplate {
+    ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+        "response": {
+            "status": 200,
+            "message": format!("Information {id}"),
+            "error": false,
+            "error_code": 0
+        },
+        "info": {
+            "id": id.to_string(),
+            "tag": "tag",
+            "type": "Access credential",
+            "content": "content"
+        }
+    }))
+}
+
+fn error_response(status: StatusCode, code: u16, message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_json(json!({
+        "response": {
+            "status": status.as_u16(),
+            "message": message,
+            "error": true,
+            "error_code": code
+        },
+        "exception": {
+            "code": code,
+            "message": message,
+            "detail": null
+        }
+    }))
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_hit(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = CachedClient::builder(inner_client(fixture)).build();
+
+    for _ in 0..3 {
+        let response = client.access_protected_information(id).await.unwrap();
+
+        assert_eq!(response.info.id, id);
+        assert_eq!(response.info.content, "content");
+    }
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            hits: 2,
+            misses: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_negative_hit(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(error_response(
+            StatusCode::BAD_REQUEST,
+            1023,
+            "1023: Information not found",
+        ))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = CachedClient::builder(inner_client(fixture)).build();
+
+    for _ in 0..2 {
+        let error = client.access_protected_information(id).await.unwrap_err();
+
+        assert!(matches!(error, Error::Api(_)));
+    }
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            misses: 1,
+            negative_hits: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_stale_if_error(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            503,
+            "Service unavailable",
+        ))
+        .expect(2)
+        .mount(fixture.server())
+        .await;
+
+    let client = CachedClient::builder(inner_client(fixture))
+        .ttl(Duration::ZERO)
+        .max_staleness(Duration::from_secs(3600))
+        .build();
+
+    client.access_protected_information(id).await.unwrap();
+
+    let response = client.access_protected_information(id).await.unwrap();
+    assert_eq!(response.info.content, "content");
+
+    client.clear();
+
+    let error = client.access_protected_information(id).await.unwrap_err();
+    assert!(matches!(error, Error::Api(_)));
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            misses: 3,
+            stale_hits: 1,
+            invalidations: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_stale_if_timeout(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id).set_delay(Duration::from_secs(5)))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let inner_client = {
+        let base_url = Url::parse(&fixture.server().uri()).unwrap();
+
+        SenhaseguraClient::builder(base_url, Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+            .disable_retries()
+            .request_timeout(Duration::from_millis(500))
+            .build()
+            .unwrap()
+    };
+
+    let client = CachedClient::builder(inner_client)
+        .ttl(Duration::ZERO)
+        .max_staleness(Duration::from_secs(3600))
+        .build();
+
+    client.access_protected_information(id).await.unwrap();
+
+    let response = client.access_protected_information(id).await.unwrap();
+    assert_eq!(response.info.content, "content");
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            misses: 2,
+            stale_hits: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_stale_if_token_error(fixture: &mut Fixture) {
+    let id = 28;
+
+    // Replaces the fixture's authentication mock with one of an access token which expires
+    // right away, followed by token endpoint failures.
+    fixture.server().reset().await;
+
+    Mock::given(method(Method::POST))
+        .and(path("/iso/oauth2/token"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "access_token": "access_token",
+            "token_type": "bearer",
+            "expires_in": 0
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::POST))
+        .and(path("/iso/oauth2/token"))
+        .respond_with(ResponseTemplate::new(StatusCode::BAD_GATEWAY))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = CachedClient::builder(inner_client(fixture))
+        .ttl(Duration::ZERO)
+        .max_staleness(Duration::from_secs(3600))
+        .build();
+
+    client.access_protected_information(id).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let response = client.access_protected_information(id).await.unwrap();
+    assert_eq!(response.info.content, "content");
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            misses: 2,
+            stale_hits: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_no_stale_if_other_error(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_string("not json"))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = CachedClient::builder(inner_client(fixture))
+        .ttl(Duration::ZERO)
+        .max_staleness(Duration::from_secs(3600))
+        .build();
+
+    client.access_protected_information(id).await.unwrap();
+
+    let error = client.access_protected_information(id).await.unwrap_err();
+    assert!(matches!(error, Error::Other(_)));
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            misses: 2,
+            ..Default::default()
+        }
+    );
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cache_invalidate_on_disable(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id))
+        .expect(2)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::DELETE))
+        .and(path("/iso/pam/info/tag"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information successfully disabled",
+                "error": false,
+                "error_code": 0
+            }
+        })))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = CachedClient::builder(inner_client(fixture)).build();
+
+    client.access_protected_information(id).await.unwrap();
+    client.disable_protected_information("tag").await.unwrap();
+    client.access_protected_information(id).await.unwrap();
+
+    assert_eq!(
+        client.metrics(),
+        CacheMetrics {
+            misses: 2,
+            invalidations: 1,
+            ..Default::default()
+        }
+    );
+}
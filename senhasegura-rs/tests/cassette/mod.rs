@@ -0,0 +1,150 @@
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{
+    AccessProtectedInformationApi, Cassette, CreateProtectedInformationApi,
+    CreateProtectedInformationApiRequest, Error, SenhaseguraClient,
+};
+
+use crate::fixture::Fixture;
+
+fn client(base_url: Url, cassette: &Cassette) -> SenhaseguraClient {
+    SenhaseguraClient::builder(base_url, Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+        .disable_retries()
+        .cassette(cassette.clone())
+        .build()
+        .unwrap()
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cassette_record_replay(fixture: &mut Fixture) {
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/28"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information 28",
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": "28",
+                "tag": "db-prod",
+                "type": "Access credential",
+                "content": "password"
+            }
+        })))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("cassette.json");
+
+    // Record.
+    {
+        let cassette = Cassette::record(&file).unwrap();
+        let client = client(Url::parse(&fixture.server().uri()).unwrap(), &cassette);
+
+        let response = client.access_protected_information(28).await.unwrap();
+        assert_eq!(response.info.content, "password");
+
+        assert_eq!(cassette.interactions().len(), 2);
+    }
+
+    let recorded = std::fs::read_to_string(&file).unwrap();
+    assert!(
+        !recorded.contains(&format!("client_secret={}", Fixture::CLIENT_SECRET)),
+        "{recorded}"
+    );
+    assert!(!recorded.contains("password"), "{recorded}");
+    assert!(
+        !recorded.contains("\\\"access_token\\\":\\\"access_token\\\""),
+        "{recorded}"
+    );
+
+    // Replay, without reaching the server.
+    let cassette = Cassette::replay(&file).unwrap();
+    let client = client(Url::parse("http://127.0.0.1:9").unwrap(), &cassette);
+
+    let response = client.access_protected_information(28).await.unwrap();
+    assert_eq!(response.info.id, 28);
+    assert_eq!(response.info.tag.as_deref(), Some("db-prod"));
+    assert_eq!(response.info.content, "[REDACTED]");
+
+    assert_eq!(cassette.remaining(), 0);
+
+    // Every interaction is replayed at most once.
+    let error = client.access_protected_information(28).await.unwrap_err();
+    assert!(matches!(error, Error::Other(_)), "{error:?}");
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_cassette_replay_unmatched(fixture: &mut Fixture) {
+    Mock::given(method(Method::POST))
+        .and(path("/iso/pam/info"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::CREATED).set_body_json(json!({
+                "response": {
+                    "status": 201,
+                    "message": "Information successfully registered!",
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "content": "password",
+                    "identifier": "db-prod"
+                }
+            })),
+        )
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("cassette.json");
+
+    let request = |identifier: &str| CreateProtectedInformationApiRequest {
+        name: None,
+        content: "password".to_string(),
+        identifier: Some(identifier.to_string()),
+        r#type: None,
+    };
+
+    {
+        let cassette = Cassette::record(&file).unwrap();
+        let client = client(Url::parse(&fixture.server().uri()).unwrap(), &cassette);
+
+        client
+            .create_protected_information(request("db-prod"))
+            .await
+            .unwrap();
+    }
+
+    let cassette = Cassette::replay(&file).unwrap();
+    let client = client(Url::parse("http://127.0.0.1:9").unwrap(), &cassette);
+
+    // Request bodies are matched after scrubbing, thus regardless of their content.
+    let error = client
+        .create_protected_information(request("db-dr"))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::Other(_)), "{error:?}");
+
+    let response = client
+        .create_protected_information(CreateProtectedInformationApiRequest {
+            content: "another password".to_string(),
+            ..request("db-prod")
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.info.identifier.as_deref(), Some("db-prod"));
+}
@@ -0,0 +1,140 @@
+use senhasegura_rs::{ClientConfig, SenhaseguraClientBuilder};
+
+#[test]
+fn test_config_from_file() {
+    let dir = tempfile::tempdir().unwrap();
+
+    std::fs::write(dir.path().join("secret"), "client_secret\n").unwrap();
+
+    let toml = dir.path().join("config.toml");
+    std::fs::write(
+        &toml,
+        r#"
+[default]
+base_url = "https://senhasegura.acme.com"
+client_id = "client_id"
+client_secret_file = "secret"
+request_timeout_secs = 30
+
+[missing_secret]
+base_url = "https://senhasegura.acme.com"
+client_id = "client_id"
+
+[both_secrets]
+base_url = "https://senhasegura.acme.com"
+client_id = "client_id"
+client_secret = "client_secret"
+client_secret_file = "secret"
+"#,
+    )
+    .unwrap();
+
+    let config = ClientConfig::from_file(&toml, "default").unwrap();
+    assert_eq!(config.client_secret_file, Some(dir.path().join("secret")));
+    assert_eq!(config.request_timeout_secs, Some(30));
+    assert!(!format!("{config:?}").contains("client_secret\""));
+
+    SenhaseguraClientBuilder::from_config_file(&toml, "default")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let error = SenhaseguraClientBuilder::from_config_file(&toml, "missing_secret")
+        .err()
+        .unwrap();
+    assert!(
+        error.to_string().contains("missing client_secret"),
+        "{error}"
+    );
+
+    let error = SenhaseguraClientBuilder::from_config_file(&toml, "both_secrets")
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("exclusive"), "{error}");
+
+    let error = ClientConfig::from_file(&toml, "prod").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("available: both_secrets, default"),
+        "{error}"
+    );
+
+    let yaml = dir.path().join("config.yaml");
+    std::fs::write(
+        &yaml,
+        r#"
+default:
+  base_url: https://senhasegura.acme.com
+  client_id: client_id
+  client_secret: client_secret
+  max_n_retries: 5
+"#,
+    )
+    .unwrap();
+
+    let config = ClientConfig::from_file(&yaml, "default").unwrap();
+    assert_eq!(config.client_secret.as_deref(), Some("client_secret"));
+    assert_eq!(config.max_n_retries, Some(5));
+
+    std::fs::write(
+        &yaml,
+        "default:\n  base_uri: https://senhasegura.acme.com\n",
+    )
+    .unwrap();
+
+    let error = ClientConfig::from_file(&yaml, "default").unwrap_err();
+    assert!(error.to_string().contains("base_uri"), "{error}");
+}
+
+#[test]
+fn test_config_from_vars() {
+    let vars = [
+        ("SENHASEGURA_BASE_URL", "https://senhasegura.acme.com"),
+        ("SENHASEGURA_CLIENT_ID", "client_id"),
+        ("SENHASEGURA_CLIENT_SECRET", "client_secret"),
+        ("SENHASEGURA_MAX_N_RETRIES", ""),
+    ];
+
+    let config = ClientConfig::from_vars(vars).unwrap();
+    assert_eq!(config.client_id.as_deref(), Some("client_id"));
+    assert_eq!(config.max_n_retries, None);
+
+    SenhaseguraClientBuilder::from_vars(vars)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Settings of higher precedence take over.
+    let config = ClientConfig {
+        client_secret_file: Some("secret".into()),
+        ..Default::default()
+    }
+    .or(config);
+    assert_eq!(config.client_secret, None);
+    assert_eq!(config.client_id.as_deref(), Some("client_id"));
+
+    let error = ClientConfig::from_vars([("SENHASEGURA_REQUEST_TIMEOUT_SECS", "ten")]).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("invalid SENHASEGURA_REQUEST_TIMEOUT_SECS"),
+        "{error}"
+    );
+
+    let error = SenhaseguraClientBuilder::from_vars(
+        vars.into_iter()
+            .chain([("SENHASEGURA_REQUEST_TIMEOUT_SECS", "0")]),
+    )
+    .err()
+    .unwrap();
+    assert!(
+        error.to_string().contains("request_timeout_secs"),
+        "{error}"
+    );
+
+    let error = SenhaseguraClientBuilder::from_vars(vars[1..].iter().copied())
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("missing base_url"), "{error}");
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{
+    AccessProtectedInformationApi, CachedClient, DynAccessProtectedInformationApi,
+    DynSenhaseguraApi, SenhaseguraClient,
+};
+
+use crate::fixture::Fixture;
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_dyn_senhasegura_api(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": format!("Information {id}"),
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": id.to_string(),
+                "tag": null,
+                "type": "Access credential",
+                "content": "content"
+            }
+        })))
+        .expect(3)
+        .mount(fixture.server())
+        .await;
+
+    let client: Arc<dyn DynSenhaseguraApi> = Arc::new(
+        SenhaseguraClient::builder(
+            Url::parse(&fixture.server().uri()).unwrap(),
+            Fixture::CLIENT_ID,
+            Fixture::CLIENT_SECRET,
+        )
+        .build()
+        .unwrap(),
+    );
+
+    // Object-safe style.
+    let response = client
+        .access_protected_information_dyn(id.into())
+        .await
+        .unwrap();
+    assert_eq!(response.info.content, "content");
+
+    // Generic style, through the trait object.
+    let response = client.access_protected_information(id).await.unwrap();
+    assert_eq!(response.info.content, "content");
+
+    // Trait objects may be wrapped like any other implementation (i.e. one miss, one hit).
+    let client = CachedClient::builder(client).build();
+
+    client.access_protected_information(id).await.unwrap();
+    client.access_protected_information(id).await.unwrap();
+}
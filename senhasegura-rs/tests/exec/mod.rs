@@ -0,0 +1,72 @@
+use senhasegura_rs::{
+    testing::{FakeProtectedInformation, FakeSenhasegura},
+    EnvMapping, Error, ExecCommand,
+};
+
+fn fake() -> FakeSenhasegura {
+    let fake = FakeSenhasegura::new();
+
+    fake.insert(FakeProtectedInformation {
+        content: "password".to_string(),
+        tag: Some("db-prod".to_string()),
+        ..Default::default()
+    });
+
+    fake
+}
+
+#[test]
+fn test_env_mapping() {
+    let mapping: EnvMapping = "DB_PASS=info:db-prod".parse().unwrap();
+    assert_eq!(mapping.name, "DB_PASS");
+    assert_eq!(mapping.id, "db-prod");
+
+    let mapping: EnvMapping = "DB_PASS=info:28".parse().unwrap();
+    assert_eq!(mapping.id, 28);
+
+    assert!("DB_PASS".parse::<EnvMapping>().is_err());
+    assert!("=info:28".parse::<EnvMapping>().is_err());
+    assert!("DB_PASS=28".parse::<EnvMapping>().is_err());
+    assert!("DB_PASS=info:".parse::<EnvMapping>().is_err());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_exec() {
+    let fake = fake();
+
+    let status = ExecCommand::new("sh")
+        .args([
+            "-c",
+            r#"test "$DB_PASS" = password && test "$PGPASSWORD" = password && test "$ID" = password && exit 7"#,
+        ])
+        .secret_env("DB_PASS", "db-prod")
+        .secret_env("PGPASSWORD", "db-prod")
+        .secret_env("ID", 1)
+        .run(&fake)
+        .await
+        .unwrap();
+
+    assert_eq!(status.code(), Some(7));
+
+    // Variables mapped to the same identifier are resolved by a single request.
+    assert_eq!(fake.n_requests(), 2);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_exec_unresolved() {
+    let dir = tempfile::tempdir().unwrap();
+    let marker = dir.path().join("spawned");
+
+    let error = ExecCommand::new("touch")
+        .arg(&marker)
+        .secret_env("DB_PASS", "db-prod")
+        .secret_env("API_KEY", "api")
+        .run(&fake())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Api(_)), "{error:?}");
+    assert!(!marker.exists());
+}
@@ -0,0 +1,271 @@
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use serde_json::json;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use senhasegura_rs::{
+    with_served_by, AccessProtectedInformationApi, CreateProtectedInformationApi,
+    CreateProtectedInformationApiRequest, Error, FailoverPolicy, SenhaseguraClient,
+};
+
+use crate::fixture::Fixture;
+
+/// Starts an instance, which serves protected information 28 with the content.
+async fn instance(content: &str) -> MockServer {
+    let server = MockServer::start().await;
+
+    mount(&server, content).await;
+
+    server
+}
+
+async fn mount(server: &MockServer, content: &str) {
+    Mock::given(method(Method::POST))
+        .and(path("/iso/oauth2/token"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "access_token": format!("access_token_{content}"),
+            "token_type": "bearer",
+            "expires_in": 3600
+        })))
+        .mount(server)
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/28"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information 28",
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": "28",
+                "tag": null,
+                "type": "Access credential",
+                "content": content
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn unavailable(server: &MockServer) {
+    server.reset().await;
+
+    Mock::given(wiremock::matchers::any())
+        .respond_with(ResponseTemplate::new(StatusCode::SERVICE_UNAVAILABLE))
+        .mount(server)
+        .await;
+}
+
+fn url(server: &MockServer) -> Url {
+    Url::parse(&format!("{}/", server.uri())).unwrap()
+}
+
+async fn n_requests(server: &MockServer, path: &str) -> usize {
+    server
+        .received_requests()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter(|request| request.url.path() == path)
+        .count()
+}
+
+#[tokio::test]
+async fn test_failover() {
+    let primary = instance("primary").await;
+    let secondary = instance("secondary").await;
+
+    let client =
+        SenhaseguraClient::builder(url(&primary), Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+            .disable_retries()
+            .failover_instance(url(&secondary))
+            .failover_policy(FailoverPolicy {
+                health_check_interval: Duration::from_millis(100),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+    let (response, served_by) = with_served_by(client.access_protected_information(28)).await;
+    assert_eq!(response.unwrap().info.content, "primary");
+    assert_eq!(served_by, Some(url(&primary)));
+
+    unavailable(&primary).await;
+
+    let (response, served_by) = with_served_by(client.access_protected_information(28)).await;
+    assert_eq!(response.unwrap().info.content, "secondary");
+    assert_eq!(served_by, Some(url(&secondary)));
+
+    // The secondary has its own token context.
+    assert_eq!(n_requests(&secondary, "/iso/oauth2/token").await, 1);
+
+    // The secondary is sticky, thus the primary isn't tried again until it's probed.
+    primary.reset().await;
+
+    let response = client.access_protected_information(28).await.unwrap();
+    assert_eq!(response.info.content, "secondary");
+    assert!(primary.received_requests().await.unwrap().is_empty());
+
+    // Once the primary is healthy again, a probe (triggered by a request after the interval)
+    // fails back to it.
+    mount(&primary, "primary").await;
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    client.access_protected_information(28).await.unwrap();
+
+    for _ in 0..50 {
+        let response = client.access_protected_information(28).await.unwrap();
+
+        if response.info.content == "primary" {
+            return;
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    panic!("didn't fail back to the primary");
+}
+
+#[tokio::test]
+async fn test_failover_api_error() {
+    let primary = MockServer::start().await;
+    let secondary = instance("secondary").await;
+
+    mount(&primary, "primary").await;
+
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/29"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::NOT_FOUND).set_body_json(json!({
+                "response": {
+                    "status": 404,
+                    "message": "Information not found",
+                    "error": true,
+                    "error_code": 1023
+                },
+                "exception": {
+                    "code": 1023,
+                    "message": "Information not found",
+                    "detail": null
+                }
+            })),
+        )
+        .mount(&primary)
+        .await;
+
+    let client =
+        SenhaseguraClient::builder(url(&primary), Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+            .disable_retries()
+            .failover_instance(url(&secondary))
+            .build()
+            .unwrap();
+
+    // Client errors aren't failed over, since the instance is available.
+    let (result, served_by) = with_served_by(client.access_protected_information(29)).await;
+    assert!(matches!(result, Err(Error::Api(_))));
+    assert_eq!(served_by, Some(url(&primary)));
+
+    assert!(secondary.received_requests().await.unwrap().is_empty());
+}
+
+async fn mount_create(server: &MockServer, delay: Duration) {
+    Mock::given(method(Method::POST))
+        .and(path("/iso/pam/info"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::CREATED)
+                .set_body_json(json!({
+                    "response": {
+                        "status": 201,
+                        "message": "Information created",
+                        "error": false,
+                        "error_code": 0
+                    },
+                    "info": {
+                        "name": null,
+                        "type": null,
+                        "service": null,
+                        "url": null,
+                        "content": "content",
+                        "users_allowed": null,
+                        "identifier": "tag"
+                    }
+                }))
+                .set_delay(delay),
+        )
+        .mount(server)
+        .await;
+}
+
+fn create_request() -> CreateProtectedInformationApiRequest {
+    CreateProtectedInformationApiRequest {
+        r#type: None,
+        name: None,
+        content: "content".to_string(),
+        identifier: Some("tag".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_failover_create_timeout() {
+    let primary = instance("primary").await;
+    let secondary = instance("secondary").await;
+
+    mount_create(&primary, Duration::from_secs(5)).await;
+    mount_create(&secondary, Duration::ZERO).await;
+
+    let client =
+        SenhaseguraClient::builder(url(&primary), Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+            .request_timeout(Duration::from_millis(200))
+            .disable_retries()
+            .failover_instance(url(&secondary))
+            .build()
+            .unwrap();
+
+    // The primary may have created the item before timing out, thus it isn't created again on the
+    // secondary.
+    let error = client
+        .create_protected_information(create_request())
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::Transport(e) if e.is_timeout()));
+
+    assert_eq!(n_requests(&primary, "/iso/pam/info").await, 1);
+    assert_eq!(n_requests(&secondary, "/iso/pam/info").await, 0);
+}
+
+#[tokio::test]
+async fn test_failover_create_connect_error() {
+    let secondary = instance("secondary").await;
+
+    mount_create(&secondary, Duration::ZERO).await;
+
+    // Nothing listens on the primary, thus the request never reaches it.
+    let primary = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+
+        Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap()
+    };
+
+    let client = SenhaseguraClient::builder(primary, Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+        .disable_retries()
+        .failover_instance(url(&secondary))
+        .build()
+        .unwrap();
+
+    let response = client
+        .create_protected_information(create_request())
+        .await
+        .unwrap();
+    assert_eq!(response.info.identifier.as_deref(), Some("tag"));
+
+    assert_eq!(n_requests(&secondary, "/iso/pam/info").await, 1);
+}
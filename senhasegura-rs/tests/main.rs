@@ -1,3 +1,30 @@
 mod fixture;
 
+mod cache;
+
+mod cassette;
+
+mod config;
+
+mod dynamic;
+
+mod exec;
+
+mod failover;
+
+mod otel;
+
 mod pam_core;
+
+mod pool;
+
+mod snapshot;
+
+mod template;
+
+mod watcher;
+
+mod testing;
+
+#[cfg(feature = "uniffi")]
+mod uniffi;
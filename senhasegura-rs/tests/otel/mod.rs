@@ -0,0 +1,237 @@
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use opentelemetry::{
+    trace::{SpanId, SpanKind, Status},
+    KeyValue, Value,
+};
+use opentelemetry_sdk::{
+    metrics::{
+        data::{AggregatedMetrics, MetricData},
+        InMemoryMetricExporter, PeriodicReader, SdkMeterProvider,
+    },
+    propagation::TraceContextPropagator,
+    trace::{InMemorySpanExporter, SdkTracerProvider, SpanData},
+};
+use serde_json::json;
+use url::Url;
+use wiremock::{
+    matchers::{header_exists, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use senhasegura_rs::{AccessProtectedInformationApi, FailoverPolicy, SenhaseguraClient};
+
+const TAG: &str = "otel-db";
+const CONTENT: &str = "otel-password";
+
+fn attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a Value> {
+    span.attributes
+        .iter()
+        .find(|KeyValue { key: k, .. }| k.as_str() == key)
+        .map(|KeyValue { value, .. }| value)
+}
+
+#[tokio::test]
+async fn test_otel() {
+    let span_exporter = InMemorySpanExporter::default();
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_simple_exporter(span_exporter.clone())
+        .build();
+    opentelemetry::global::set_tracer_provider(tracer_provider.clone());
+
+    let metric_exporter = InMemoryMetricExporter::default();
+    let meter_provider = SdkMeterProvider::builder()
+        .with_reader(PeriodicReader::builder(metric_exporter.clone()).build())
+        .build();
+    opentelemetry::global::set_meter_provider(meter_provider.clone());
+
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let server = MockServer::start().await;
+
+    Mock::given(method(Method::POST))
+        .and(path("/iso/oauth2/token"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "access_token": "access_token",
+            "token_type": "bearer",
+            "expires_in": 3600
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{TAG}")))
+        .and(header_exists("traceparent"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information 28",
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": "28",
+                "tag": TAG,
+                "type": "Access credential",
+                "content": CONTENT
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SenhaseguraClient::builder(
+        Url::parse(&server.uri()).unwrap(),
+        "client_id",
+        "client_secret",
+    )
+    .build()
+    .unwrap();
+
+    let response = client.access_protected_information(TAG).await.unwrap();
+    assert_eq!(response.info.content, CONTENT);
+
+    tracer_provider.force_flush().unwrap();
+    meter_provider.force_flush().unwrap();
+
+    // Other tests might record spans concurrently, thus only those of this server are considered.
+    let server_port = Url::parse(&server.uri()).unwrap().port().unwrap();
+
+    let spans = span_exporter.get_finished_spans().unwrap();
+
+    let client_spans = spans
+        .iter()
+        .filter(|span| {
+            span.span_kind == SpanKind::Client
+                && attribute(span, "server.port") == Some(&Value::I64(server_port.into()))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(client_spans.len(), 2);
+
+    let operation_span = spans
+        .iter()
+        .find(|span| span.span_context.span_id() == client_spans[0].parent_span_id)
+        .unwrap();
+    assert_eq!(operation_span.name, "access_protected_information");
+    assert_eq!(
+        attribute(operation_span, "senhasegura.identifier.kind"),
+        Some(&Value::from("tag"))
+    );
+    assert!(attribute(operation_span, "senhasegura.instance").is_some());
+
+    for span in &client_spans {
+        assert_eq!(span.parent_span_id, operation_span.span_context.span_id());
+        assert_eq!(
+            attribute(span, "http.response.status_code"),
+            Some(&Value::I64(200))
+        );
+    }
+
+    let api_span = client_spans
+        .iter()
+        .find(|span| attribute(span, "http.request.method") == Some(&Value::from("GET")))
+        .unwrap();
+    assert_eq!(
+        attribute(api_span, "url.template"),
+        Some(&Value::from("/iso/pam/info/{identifier}"))
+    );
+    assert!(attribute(api_span, "url.full").is_none());
+
+    // Protected information content and tags are never recorded.
+    for span in spans.iter() {
+        for KeyValue { value, .. } in &span.attributes {
+            assert!(!value.as_str().contains(CONTENT));
+            assert!(!value.as_str().contains(TAG));
+        }
+    }
+
+    let metrics = metric_exporter.get_finished_metrics().unwrap();
+    let names = metrics
+        .iter()
+        .flat_map(|resource| resource.scope_metrics())
+        .flat_map(|scope| scope.metrics())
+        .map(|metric| metric.name().to_string())
+        .collect::<Vec<_>>();
+
+    for name in [
+        "senhasegura.client.requests",
+        "senhasegura.client.request.duration",
+        "senhasegura.client.token.refreshes",
+    ] {
+        assert!(names.iter().any(|n| n == name), "missing metric {name}");
+    }
+
+    // Background failover probes are traced on their own, and their token refreshes recorded.
+    span_exporter.reset();
+    metric_exporter.reset();
+
+    // Nothing listens on the discard port, thus the primary instance is unreachable.
+    let primary = Url::parse("http://127.0.0.1:9").unwrap();
+
+    let client = SenhaseguraClient::builder(primary.clone(), "client_id", "client_secret")
+        .disable_retries()
+        .failover_instance(Url::parse(&server.uri()).unwrap())
+        .failover_policy(FailoverPolicy {
+            health_check_interval: Duration::from_millis(100),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    // The secondary instance serves the requests (i.e. not found errors), once the primary fails.
+    client
+        .access_protected_information("otel-missing")
+        .await
+        .unwrap_err();
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    // Fails over again, probing the primary instance in the background.
+    client
+        .access_protected_information("otel-missing")
+        .await
+        .unwrap_err();
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    tracer_provider.force_flush().unwrap();
+    meter_provider.force_flush().unwrap();
+
+    let spans = span_exporter.get_finished_spans().unwrap();
+
+    let probe_span = spans
+        .iter()
+        .find(|span| {
+            span.name == "probe_instance"
+                && attribute(span, "senhasegura.instance")
+                    == Some(&Value::from(primary.to_string()))
+        })
+        .unwrap();
+    assert!(matches!(probe_span.status, Status::Error { .. }));
+    assert_eq!(probe_span.parent_span_id, SpanId::INVALID);
+
+    assert!(spans.iter().any(|span| {
+        span.span_kind == SpanKind::Client
+            && span.parent_span_id == probe_span.span_context.span_id()
+    }));
+
+    let failed_refreshes = metric_exporter
+        .get_finished_metrics()
+        .unwrap()
+        .iter()
+        .flat_map(|resource| resource.scope_metrics())
+        .flat_map(|scope| scope.metrics())
+        .filter(|metric| metric.name() == "senhasegura.client.token.refreshes")
+        .filter_map(|metric| match metric.data() {
+            AggregatedMetrics::U64(MetricData::Sum(sum)) => Some(
+                sum.data_points()
+                    .filter(|point| point.attributes().any(|kv| kv.key.as_str() == "error.type"))
+                    .map(|point| point.value())
+                    .sum::<u64>(),
+            ),
+            _ => None,
+        })
+        .sum::<u64>();
+    assert!(failed_refreshes >= 3, "{failed_refreshes}");
+}
@@ -0,0 +1,76 @@
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{BatchAccessProtectedInformationApi, Error};
+
+use crate::fixture::Fixture;
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_access_protected_information_batch(fixture: &mut Fixture) {
+    for id in [28, 29] {
+        Mock::given(method(Method::GET))
+            .and(path(format!("/iso/pam/info/{id}")))
+            .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+                "response": {
+                    "status": 200,
+                    "message": format!("Information {id}"),
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "id": id.to_string(),
+                    "tag": null,
+                    "type": "Access credential",
+                    "content": format!("content {id}")
+                }
+            })))
+            .expect(1)
+            .mount(fixture.server())
+            .await;
+    }
+
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/30"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::BAD_REQUEST).set_body_json(json!({
+                "response": {
+                    "status": 400,
+                    "message": "1023: Information not found",
+                    "error": true,
+                    "error_code": 1023
+                },
+                "exception": {
+                    "code": 1023,
+                    "message": "1023: Information not found",
+                    "detail": null
+                }
+            })),
+        )
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    // The fixture only allows a single authentication, thus all requests must share the same
+    // access token.
+    let results = fixture
+        .client()
+        .access_protected_information_batch(vec![28.into(), 29.into(), 30.into(), 28.into()], 2)
+        .await;
+
+    assert_eq!(results.len(), 3);
+
+    for id in [28, 29] {
+        let response = results[&id.into()].as_ref().unwrap();
+
+        assert_eq!(response.info.id, id);
+        assert_eq!(response.info.content, format!("content {id}"));
+    }
+
+    assert!(matches!(results[&30.into()], Err(Error::Api(_))));
+}
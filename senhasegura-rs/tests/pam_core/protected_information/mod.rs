@@ -1,3 +1,4 @@
 mod access;
+mod batch_access;
 mod create;
 mod disable;
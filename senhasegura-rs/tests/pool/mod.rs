@@ -0,0 +1,137 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{
+    AccessProtectedInformationApi, SenhaseguraClientBuilder, SenhaseguraClientPool,
+};
+
+use crate::fixture::Fixture;
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_pool(fixture: &mut Fixture) {
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/28"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information 28",
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": "28",
+                "tag": null,
+                "type": "Access credential",
+                "content": "password"
+            }
+        })))
+        .mount(fixture.server())
+        .await;
+
+    let base_url = Url::parse(&fixture.server().uri()).unwrap();
+    let n_builds = Arc::new(AtomicUsize::new(0));
+
+    let pool = SenhaseguraClientPool::builder({
+        let n_builds = n_builds.clone();
+
+        move |tenant: &&'static str| {
+            let tenant = *tenant;
+            let base_url = base_url.clone();
+
+            n_builds.fetch_add(1, Ordering::Relaxed);
+
+            async move {
+                if tenant == "unknown" {
+                    return Err(anyhow::anyhow!("unknown tenant").into());
+                }
+
+                Ok(SenhaseguraClientBuilder::new(
+                    base_url,
+                    Fixture::CLIENT_ID,
+                    Fixture::CLIENT_SECRET,
+                )
+                .disable_retries())
+            }
+        }
+    })
+    .build();
+
+    // Concurrent requests for the same tenant build its client only once.
+    let clients = futures::future::try_join_all((0..8).map(|_| pool.get(&"payments")))
+        .await
+        .unwrap();
+    assert!(clients
+        .iter()
+        .all(|client| Arc::ptr_eq(client, &clients[0])));
+    assert_eq!(n_builds.load(Ordering::Relaxed), 1);
+
+    let response = clients[0].access_protected_information(28).await.unwrap();
+    assert_eq!(response.info.content, "password");
+
+    pool.get(&"billing").await.unwrap();
+    assert_eq!(pool.len(), 2);
+    assert_eq!(n_builds.load(Ordering::Relaxed), 2);
+
+    // Tenants with the same base URL share the HTTP client.
+    assert_eq!(pool.n_http_clients(), 1);
+
+    // Failures aren't cached.
+    assert!(pool.get(&"unknown").await.is_err());
+    assert!(pool.get(&"unknown").await.is_err());
+    assert_eq!(n_builds.load(Ordering::Relaxed), 4);
+
+    pool.remove(&"billing");
+    pool.remove(&"unknown");
+    assert_eq!(pool.len(), 1);
+    assert_eq!(pool.n_http_clients(), 1);
+
+    let client = pool.get(&"payments").await.unwrap();
+    assert!(Arc::ptr_eq(&client, &clients[0]));
+}
+
+#[tokio::test]
+async fn test_pool_idle_eviction() {
+    let pool = SenhaseguraClientPool::builder(|tenant: &String| {
+        let tenant = tenant.clone();
+
+        async move {
+            Ok(SenhaseguraClientBuilder::new(
+                "https://senhasegura.acme.com".parse().unwrap(),
+                tenant,
+                "client_secret",
+            ))
+        }
+    })
+    .idle_timeout(Duration::from_millis(50))
+    .build();
+
+    let client = pool.get(&"payments".to_string()).await.unwrap();
+    assert_eq!(pool.len(), 1);
+    assert_eq!(pool.n_http_clients(), 1);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // HTTP clients are dropped along with the last tenant which used them.
+    pool.evict_idle();
+    assert!(pool.is_empty());
+    assert_eq!(pool.n_http_clients(), 0);
+
+    // Evicted tenants are built again on their next use.
+    let other = pool.get(&"payments".to_string()).await.unwrap();
+    assert!(!Arc::ptr_eq(&client, &other));
+}
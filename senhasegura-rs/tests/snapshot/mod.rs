@@ -0,0 +1,160 @@
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{
+    testing::{FakeProtectedInformation, FakeSenhasegura},
+    with_served_from_snapshot, AccessProtectedInformationApi, Error, SenhaseguraClient, Snapshot,
+    SnapshotClient, SnapshotKey,
+};
+
+use crate::fixture::Fixture;
+
+async fn mount_access(fixture: &Fixture, id: i32, tag: &str) {
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": format!("Information {id}"),
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": id.to_string(),
+                "tag": tag,
+                "type": "Access credential",
+                "content": format!("content {id}")
+            }
+        })))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_snapshot_roundtrip(fixture: &mut Fixture) {
+    mount_access(fixture, 28, "db-prod").await;
+    mount_access(fixture, 29, "db-dr").await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("secrets.snapshot");
+
+    // Existing files are replaced, along with their permissions.
+    std::fs::write(&file, "stale").unwrap();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o644)).unwrap();
+    }
+
+    let key = SnapshotKey::generate();
+
+    Snapshot::export(fixture.client(), [28, 29])
+        .await
+        .unwrap()
+        .write(&file, &key)
+        .unwrap();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = std::fs::metadata(&file).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // Only the snapshot itself is left behind.
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+    let snapshot = Snapshot::read(&file, &key).unwrap();
+
+    assert_eq!(snapshot.items().len(), 2);
+    assert_eq!(
+        snapshot.get(&"db-dr".into()).unwrap().info.content,
+        "content 29"
+    );
+
+    assert!(Snapshot::read(&file, &SnapshotKey::generate()).is_err());
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_snapshot_client_fallback(fixture: &mut Fixture) {
+    mount_access(fixture, 28, "db-prod").await;
+
+    let snapshot = Snapshot::export(fixture.client(), [28]).await.unwrap();
+
+    // Nothing listens on the discard port, thus the API is unreachable.
+    let unreachable = SenhaseguraClient::builder(
+        Url::parse("http://127.0.0.1:9").unwrap(),
+        Fixture::CLIENT_ID,
+        Fixture::CLIENT_SECRET,
+    )
+    .disable_retries()
+    .build()
+    .unwrap();
+
+    let client = SnapshotClient::new(unreachable, snapshot);
+
+    let (response, from_snapshot) =
+        with_served_from_snapshot(client.access_protected_information("db-prod")).await;
+    let response = response.unwrap();
+
+    assert!(from_snapshot);
+    assert_eq!(response.info.id, 28);
+    assert_eq!(response.info.content, "content 28");
+
+    let (response, from_snapshot) =
+        with_served_from_snapshot(client.access_protected_information(29)).await;
+
+    assert!(response.is_err());
+    assert!(!from_snapshot);
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_snapshot_client_no_fallback_if_other_error(fixture: &mut Fixture) {
+    let fake = FakeSenhasegura::new();
+
+    fake.insert(FakeProtectedInformation {
+        content: "password".to_string(),
+        tag: Some("db-prod".to_string()),
+        ..Default::default()
+    });
+
+    let snapshot = Snapshot::export(&fake, ["db-prod"]).await.unwrap();
+
+    // The API is reachable, but its response can't be decoded.
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/db-prod"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_string("not json"))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let inner = SenhaseguraClient::builder(
+        Url::parse(&fixture.server().uri()).unwrap(),
+        Fixture::CLIENT_ID,
+        Fixture::CLIENT_SECRET,
+    )
+    .disable_retries()
+    .build()
+    .unwrap();
+
+    let client = SnapshotClient::new(inner, snapshot);
+
+    let (response, from_snapshot) =
+        with_served_from_snapshot(client.access_protected_information("db-prod")).await;
+
+    assert!(matches!(response.unwrap_err(), Error::Other(_)));
+    assert!(!from_snapshot);
+}
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use senhasegura_rs::{
+    testing::{FakeProtectedInformation, FakeSenhasegura},
+    AccessProtectedInformationResult, ProtectedInformationIdentifier, Template, TemplateRenderer,
+};
+
+fn fake() -> FakeSenhasegura {
+    let fake = FakeSenhasegura::new();
+
+    fake.insert(FakeProtectedInformation {
+        content: "password".to_string(),
+        tag: Some("db-prod".to_string()),
+        r#type: Some("Access credential".to_string()),
+        ..Default::default()
+    });
+
+    fake
+}
+
+#[tokio::test]
+async fn test_template_render() {
+    let template = Template::parse(
+        "user = {{ info:db-prod type }}\npassword = {{info:1}}\nid = {{ info:db-prod id }}\nname = {{ .Values.name }}\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        template.identifiers(),
+        vec!["db-prod".into(), 1.into()] as Vec<ProtectedInformationIdentifier>
+    );
+
+    let rendered = template.render(&fake()).await.unwrap();
+    assert_eq!(
+        rendered,
+        "user = Access credential\npassword = password\nid = 1\nname = {{ .Values.name }}\n"
+    );
+
+    let values = HashMap::from([(
+        "db-prod".into(),
+        AccessProtectedInformationResult {
+            id: 28,
+            tag: None,
+            r#type: None,
+            content: "other password".to_string(),
+        },
+    )]);
+    assert!(template.render_with(&values).is_err());
+}
+
+#[test]
+fn test_template_parse_errors() {
+    let error = Template::parse("a\nb = {{ info:db-prod name }}").unwrap_err();
+    assert!(error.to_string().contains("line 2"), "{error}");
+
+    assert!(Template::parse("{{ info: }}").is_err());
+    assert!(Template::parse("{{ info:db-prod content extra }}").is_err());
+    assert!(Template::parse("{{ info:db-prod unknown }}").is_err());
+}
+
+#[tokio::test]
+async fn test_template_renderer() {
+    let dir = tempfile::tempdir().unwrap();
+    let destination = dir.path().join("app.conf");
+
+    let fake = fake();
+    let renderer = TemplateRenderer::new(
+        Template::parse("password = {{ info:db-prod }}").unwrap(),
+        &destination,
+    );
+
+    assert!(renderer.render(&fake).await.unwrap());
+    assert_eq!(
+        std::fs::read_to_string(&destination).unwrap(),
+        "password = password"
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = std::fs::metadata(&destination)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // The file isn't rewritten if its content didn't change.
+    assert!(!renderer.render(&fake).await.unwrap());
+
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_template_renderer_watch() {
+    let dir = tempfile::tempdir().unwrap();
+    let destination = dir.path().join("app.conf");
+    let reloads = dir.path().join("reloads");
+
+    let fake = fake();
+    let renderer = TemplateRenderer::new(
+        Template::parse("password = {{ info:db-prod }}").unwrap(),
+        &destination,
+    )
+    .reload_command(["sh", "-c", &format!("echo >> {}", reloads.display())]);
+
+    let task = tokio::spawn({
+        let fake = fake.clone();
+
+        async move {
+            renderer
+                .watch(Arc::new(fake), Duration::from_millis(50))
+                .await
+        }
+    });
+
+    let read = |path| std::fs::read_to_string(path).unwrap_or_default();
+
+    wait_until(|| read(&destination) == "password = password").await;
+
+    fake.insert(FakeProtectedInformation {
+        content: "new password".to_string(),
+        tag: Some("db-prod".to_string()),
+        ..Default::default()
+    });
+
+    wait_until(|| read(&destination) == "password = new password").await;
+    wait_until(|| read(&reloads).lines().count() == 2).await;
+
+    task.abort();
+}
+
+async fn wait_until(f: impl Fn() -> bool) {
+    for _ in 0..100 {
+        if f() {
+            return;
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    panic!("condition wasn't met in time");
+}
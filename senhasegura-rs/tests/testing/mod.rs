@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use http::StatusCode;
+
+use senhasegura_rs::{
+    testing::{FakeProtectedInformation, FakeSenhasegura},
+    AccessProtectedInformationApi, CachedClient, CreateProtectedInformationApi,
+    CreateProtectedInformationApiRequest, DisableProtectedInformationApi, Error, ExceptionCode,
+    PAMCoreExceptionCode, ProtectedInformationExceptionCode,
+};
+
+fn create_request(tag: &str, content: &str) -> CreateProtectedInformationApiRequest {
+    CreateProtectedInformationApiRequest {
+        name: None,
+        content: content.to_string(),
+        identifier: Some(tag.to_string()),
+        r#type: Some("Access credential".to_string()),
+    }
+}
+
+fn exception_code(error: Error) -> Option<ProtectedInformationExceptionCode> {
+    match error {
+        Error::Api(api_error) => match api_error.exception?.code {
+            ExceptionCode::PAMCore(PAMCoreExceptionCode::ProtectedInformation(code)) => Some(code),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_fake_senhasegura() {
+    use ProtectedInformationExceptionCode::*;
+
+    let fake = FakeSenhasegura::new();
+
+    fake.create_protected_information(create_request("db-prod", "old password"))
+        .await
+        .unwrap();
+    fake.create_protected_information(create_request("db-dr", "password"))
+        .await
+        .unwrap();
+
+    // Tags are unique, thus the existing item is updated.
+    fake.create_protected_information(create_request("db-prod", "new password"))
+        .await
+        .unwrap();
+
+    let response = fake.access_protected_information(1).await.unwrap();
+    assert_eq!(response.info.tag.as_deref(), Some("db-prod"));
+    assert_eq!(response.info.content, "new password");
+
+    let response = fake.access_protected_information("2").await.unwrap();
+    assert_eq!(response.info.tag.as_deref(), Some("db-dr"));
+
+    fake.disable_protected_information("db-prod").await.unwrap();
+
+    let error = fake.access_protected_information(1).await.unwrap_err();
+    assert_eq!(exception_code(error), Some(InactiveInformation));
+
+    let error = fake.disable_protected_information(1).await.unwrap_err();
+    assert_eq!(exception_code(error), Some(InactiveInformation));
+
+    let error = fake.access_protected_information(3).await.unwrap_err();
+    assert_eq!(exception_code(error), Some(InformationNotFound));
+
+    let error = fake
+        .create_protected_information(create_request("empty", ""))
+        .await
+        .unwrap_err();
+    assert_eq!(exception_code(error), Some(MissingContentParameter));
+
+    assert_eq!(fake.items().len(), 2);
+    assert_eq!(fake.n_requests(), 10);
+}
+
+#[tokio::test]
+async fn test_fake_senhasegura_faults() {
+    let fake = FakeSenhasegura::new();
+
+    let id = fake.insert(FakeProtectedInformation {
+        content: "password".to_string(),
+        ..Default::default()
+    });
+
+    let client = CachedClient::builder(fake.clone())
+        .ttl(Duration::ZERO)
+        .max_staleness(Duration::from_secs(3600))
+        .build();
+
+    client.access_protected_information(id).await.unwrap();
+
+    // Transient failures are served from the cache.
+    fake.fail_next(1);
+
+    let response = client.access_protected_information(id).await.unwrap();
+    assert_eq!(response.info.content, "password");
+    assert_eq!(client.metrics().stale_hits, 1);
+
+    // Expired tokens aren't.
+    fake.expire_token();
+
+    match client.access_protected_information(id).await.unwrap_err() {
+        Error::Api(api_error) => assert_eq!(api_error.response.status, StatusCode::UNAUTHORIZED),
+        e => panic!("Unexpected error: {e:?}"),
+    }
+
+    client.access_protected_information(id).await.unwrap();
+
+    fake.set_latency(Duration::from_millis(100));
+
+    let result = tokio::time::timeout(
+        Duration::from_millis(10),
+        fake.access_protected_information(id),
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_fake_senhasegura_insert_default() {
+    let fake = FakeSenhasegura::new();
+
+    // Items are active by default.
+    let id = fake.insert(FakeProtectedInformation {
+        content: "password".to_string(),
+        tag: Some("db-prod".to_string()),
+        ..Default::default()
+    });
+
+    let response = fake.access_protected_information("db-prod").await.unwrap();
+    assert_eq!(response.info.id, id);
+    assert_eq!(response.info.content, "password");
+}
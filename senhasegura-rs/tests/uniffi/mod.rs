@@ -0,0 +1,210 @@
+use std::{future::Future, sync::Arc};
+
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{CreateProtectedInformationApiRequest, SenhaseguraClient, SenhaseguraError};
+
+use crate::fixture::Fixture;
+
+fn client(fixture: &Fixture) -> Arc<SenhaseguraClient> {
+    let base_url = Url::parse(&fixture.server().uri()).unwrap();
+
+    let client = SenhaseguraClient::builder(base_url, Fixture::CLIENT_ID, Fixture::CLIENT_SECRET)
+        .disable_retries()
+        .build()
+        .unwrap();
+
+    Arc::new(client)
+}
+
+/// Polls the future with a non-Tokio executor on a thread of its own (i.e. without a Tokio
+/// runtime), as foreign ones (e.g. Kotlin coroutines, Swift tasks) would.
+async fn poll_from_foreign_executor<T: Send + 'static>(
+    future: impl Future<Output = T> + Send + 'static,
+) -> T {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+
+    std::thread::spawn(move || {
+        assert!(tokio::runtime::Handle::try_current().is_err());
+
+        let _ = sender.send(futures::executor::block_on(future));
+    });
+
+    receiver.await.unwrap()
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_access_protected_information_async(fixture: &mut Fixture) {
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/28"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+            "response": {
+                "status": 200,
+                "message": "Information 28",
+                "error": false,
+                "error_code": 0
+            },
+            "info": {
+                "id": "28",
+                "tag": null,
+                "type": "Access credential",
+                "content": "password"
+            }
+        })))
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = client(fixture);
+
+    let response =
+        poll_from_foreign_executor(client.access_protected_information_async("28".to_string()))
+            .await
+            .unwrap();
+
+    assert_eq!(response.info.id, 28);
+    assert_eq!(response.info.content, "password");
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_create_protected_information_async(fixture: &mut Fixture) {
+    Mock::given(method(Method::POST))
+        .and(path("/iso/pam/info"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::CREATED).set_body_json(json!({
+                "response": {
+                    "status": 201,
+                    "message": "Information created",
+                    "error": false,
+                    "error_code": 0
+                },
+                "info": {
+                    "name": null,
+                    "type": null,
+                    "service": null,
+                    "url": null,
+                    "content": "password",
+                    "users_allowed": null,
+                    "identifier": "tag"
+                }
+            })),
+        )
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = client(fixture);
+
+    let request = CreateProtectedInformationApiRequest {
+        r#type: None,
+        name: None,
+        content: "password".to_string(),
+        identifier: Some("tag".to_string()),
+    };
+
+    let response = poll_from_foreign_executor(client.create_protected_information_async(request))
+        .await
+        .unwrap();
+
+    assert_eq!(response.info.identifier.as_deref(), Some("tag"));
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_disable_protected_information_async_not_found(fixture: &mut Fixture) {
+    Mock::given(method(Method::DELETE))
+        .and(path("/iso/pam/info/28"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::BAD_REQUEST).set_body_json(json!({
+                "response": {
+                    "status": 400,
+                    "message": "1023: Information not found",
+                    "error": true,
+                    "error_code": 1023
+                },
+                "exception": {
+                    "code": 1023,
+                    "message": "1023: Information not found",
+                    "detail": null
+                }
+            })),
+        )
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = client(fixture);
+
+    let error =
+        poll_from_foreign_executor(client.disable_protected_information_async("28".to_string()))
+            .await
+            .unwrap_err();
+
+    match error {
+        SenhaseguraError::InformationNotFound {
+            status,
+            reason,
+            exception_code,
+            detail,
+        } => {
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert_eq!(reason, "1023: Information not found");
+            assert_eq!(exception_code.map(u16::from), Some(1023));
+            assert_eq!(detail, None);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_access_protected_information_async_unknown_exception(fixture: &mut Fixture) {
+    Mock::given(method(Method::GET))
+        .and(path("/iso/pam/info/28"))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::BAD_REQUEST).set_body_json(json!({
+                "response": {
+                    "status": 400,
+                    "message": "9999: Unknown exception",
+                    "error": true,
+                    "error_code": 9999
+                },
+                "exception": {
+                    "code": 9999,
+                    "message": "9999: Unknown exception",
+                    "detail": "detail"
+                }
+            })),
+        )
+        .expect(1)
+        .mount(fixture.server())
+        .await;
+
+    let client = client(fixture);
+
+    let error =
+        poll_from_foreign_executor(client.access_protected_information_async("28".to_string()))
+            .await
+            .unwrap_err();
+
+    match error {
+        SenhaseguraError::Api {
+            exception_code,
+            detail,
+            ..
+        } => {
+            assert_eq!(exception_code.map(u16::from), Some(9999));
+            assert_eq!(detail.as_deref(), Some("detail"));
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
@@ -0,0 +1,84 @@
+use std::{sync::Arc, time::Duration};
+
+use http::{Method, StatusCode};
+use serde_json::json;
+use test_context::test_context;
+use url::Url;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use senhasegura_rs::{SecretWatcher, SenhaseguraClient};
+
+use crate::fixture::Fixture;
+
+fn access_response(id: i32, content: &str) -> ResponseTemplate {
+    ResponseTemplate::new(StatusCode::OK).set_body_json(json!({
+        "response": {
+            "status": 200,
+            "message": format!("Information {id}"),
+            "error": false,
+            "error_code": 0
+        },
+        "info": {
+            "id": id.to_string(),
+            "tag": null,
+            "type": "Access credential",
+            "content": content
+        }
+    }))
+}
+
+#[test_context(Fixture)]
+#[tokio::test]
+async fn test_secret_watcher(fixture: &mut Fixture) {
+    let id = 28;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id, "old password"))
+        .up_to_n_times(2)
+        .mount(fixture.server())
+        .await;
+
+    Mock::given(method(Method::GET))
+        .and(path(format!("/iso/pam/info/{id}")))
+        .respond_with(access_response(id, "new password"))
+        .mount(fixture.server())
+        .await;
+
+    let client = SenhaseguraClient::builder(
+        Url::parse(&fixture.server().uri()).unwrap(),
+        Fixture::CLIENT_ID,
+        Fixture::CLIENT_SECRET,
+    )
+    .build()
+    .unwrap();
+
+    let watcher = SecretWatcher::builder(Arc::new(client))
+        .watch(id)
+        .interval(Duration::from_millis(50))
+        .jitter(Duration::from_millis(10))
+        .start()
+        .await
+        .unwrap();
+
+    let mut receiver = watcher.subscribe(&id.into()).unwrap();
+    assert_eq!(receiver.borrow_and_update().info.content, "old password");
+
+    let mut changes = watcher.changes();
+
+    let change = tokio::time::timeout(Duration::from_secs(5), changes.recv())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(change.id, id);
+    assert_eq!(change.response.info.content, "new password");
+
+    assert!(receiver.has_changed().unwrap());
+    assert_eq!(receiver.borrow().info.content, "new password");
+
+    assert!(watcher.subscribe(&29.into()).is_none());
+}